@@ -0,0 +1,200 @@
+use std::{fmt, str::FromStr};
+
+use super::{patterns::PatternPositions, to_grid_position, Grid, Position, WrapMode};
+
+/// steps a grid of cells by applying a rule to every cell and its neighborhood at once.
+///
+/// the next generation is written into a second buffer, so every cell sees the neighborhood of the previous generation.
+pub struct CellularAutomaton<T>
+where
+    T: Copy,
+{
+    current: Grid<T>,
+    next: Grid<T>,
+    neighbor_offsets: Box<[Position]>,
+    wrap_mode: WrapMode,
+    generation: usize,
+}
+
+impl<T> CellularAutomaton<T>
+where
+    T: Copy + PartialEq,
+{
+    /// the neighborhood of a cell are the positions of `pattern` around it. positions outside of the grid wrap
+    /// according to `wrap_mode` and are left out of the neighborhood on non wrapping axes.
+    pub fn new(grid: Grid<T>, pattern: &dyn PatternPositions, wrap_mode: WrapMode) -> Self {
+        CellularAutomaton {
+            next: grid.clone(),
+            current: grid,
+            neighbor_offsets: pattern.get_pattern_positions(Position::ZERO),
+            wrap_mode,
+            generation: 0,
+        }
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.current
+    }
+
+    /// mutable access to the cells between steps, e.g. to place new cells
+    pub fn grid_mut(&mut self) -> &mut Grid<T> {
+        &mut self.current
+    }
+
+    pub fn into_grid(self) -> Grid<T> {
+        self.current
+    }
+
+    /// amount of steps done so far
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// calculates the next generation by calling `rule` with every cell and the values of its neighborhood.
+    ///
+    /// returns the positions of all cells whose value changed.
+    pub fn step<F>(&mut self, mut rule: F) -> Vec<Position>
+    where
+        F: FnMut(T, &[T]) -> T,
+    {
+        let size = self.current.size();
+        let mut neighbors = Vec::with_capacity(self.neighbor_offsets.len());
+        let mut changed = Vec::new();
+
+        for (index, next_value) in self.next.iter_mut().enumerate() {
+            let position = to_grid_position(index, size);
+            neighbors.clear();
+            neighbors.extend(
+                self.neighbor_offsets
+                    .iter()
+                    .filter_map(|&offset| self.current.get_wrapped(position + offset, self.wrap_mode)),
+            );
+
+            let value = self.current.values[index];
+            *next_value = rule(value, &neighbors);
+            if *next_value != value {
+                changed.push(position);
+            }
+        }
+
+        std::mem::swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+        changed
+    }
+
+    /// steps until no cell changes anymore or `max_steps` is reached, returns the amount of steps done
+    pub fn run_until_stable<F>(&mut self, max_steps: usize, mut rule: F) -> usize
+    where
+        F: FnMut(T, &[T]) -> T,
+    {
+        for steps in 0..max_steps {
+            if self.step(&mut rule).is_empty() {
+                return steps + 1;
+            }
+        }
+        max_steps
+    }
+}
+
+impl CellularAutomaton<bool> {
+    /// steps living (`true`) and dead (`false`) cells according to a birth/survival rule
+    pub fn step_life(&mut self, rule: &LifeRule) -> Vec<Position> {
+        self.step(|alive, neighbors| rule.apply(alive, neighbors))
+    }
+}
+
+/// birth/survival rule in the notation of Conway's Game of Life, e.g. `B3/S23`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LifeRule {
+    /// `birth[n]` is `true` if a dead cell with `n` living neighbors comes alive
+    pub birth: [bool; 9],
+    /// `survival[n]` is `true` if a living cell with `n` living neighbors stays alive
+    pub survival: [bool; 9],
+}
+
+impl LifeRule {
+    /// Conway's Game of Life, `B3/S23`
+    pub const CONWAY: LifeRule = LifeRule::new(&[3], &[2, 3]);
+
+    /// cave generation rule, `B5678/S45678`
+    pub const CAVES: LifeRule = LifeRule::new(&[5, 6, 7, 8], &[4, 5, 6, 7, 8]);
+
+    /// panics if a neighbor count is larger than 8
+    pub const fn new(birth: &[usize], survival: &[usize]) -> Self {
+        LifeRule {
+            birth: to_count_flags(birth),
+            survival: to_count_flags(survival),
+        }
+    }
+
+    /// next state of a cell, neighbor counts above 8 neither give birth nor survive
+    pub fn apply(&self, alive: bool, neighbors: &[bool]) -> bool {
+        let living_neighbors = neighbors.iter().filter(|&&neighbor| neighbor).count();
+        let counts = if alive { &self.survival } else { &self.birth };
+        counts.get(living_neighbors).copied().unwrap_or(false)
+    }
+}
+
+const fn to_count_flags(counts: &[usize]) -> [bool; 9] {
+    let mut flags = [false; 9];
+    let mut i = 0;
+    while i < counts.len() {
+        flags[counts[i]] = true;
+        i += 1;
+    }
+    flags
+}
+
+/// reason why a rule string could not be parsed into a `LifeRule`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LifeRuleParseError {
+    /// the rule is not of the form `B<counts>/S<counts>`
+    InvalidFormat,
+    /// a neighbor count is not a digit from 0 to 8
+    InvalidCount(char),
+}
+
+impl fmt::Display for LifeRuleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFormat => write!(f, "life rule has to be of the form B<counts>/S<counts>"),
+            Self::InvalidCount(count) => write!(f, "'{}' is not a neighbor count from 0 to 8", count),
+        }
+    }
+}
+
+impl std::error::Error for LifeRuleParseError {}
+
+impl FromStr for LifeRule {
+    type Err = LifeRuleParseError;
+
+    /// parses rules like `B3/S23`, case insensitive
+    fn from_str(rule: &str) -> Result<Self, Self::Err> {
+        let (birth, survival) = rule.trim().split_once('/').ok_or(LifeRuleParseError::InvalidFormat)?;
+        let birth = birth.strip_prefix(['B', 'b']).ok_or(LifeRuleParseError::InvalidFormat)?;
+        let survival = survival.strip_prefix(['S', 's']).ok_or(LifeRuleParseError::InvalidFormat)?;
+
+        Ok(LifeRule {
+            birth: parse_counts(birth)?,
+            survival: parse_counts(survival)?,
+        })
+    }
+}
+
+fn parse_counts(counts: &str) -> Result<[bool; 9], LifeRuleParseError> {
+    let mut flags = [false; 9];
+    for count in counts.chars() {
+        match count.to_digit(10) {
+            Some(digit) if digit <= 8 => flags[digit as usize] = true,
+            _ => return Err(LifeRuleParseError::InvalidCount(count)),
+        }
+    }
+    Ok(flags)
+}
+
+impl fmt::Display for LifeRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let counts = |flags: &[bool; 9]| (0..9).filter(|&count| flags[count]).map(|count| count.to_string()).collect::<String>();
+        write!(f, "B{}/S{}", counts(&self.birth), counts(&self.survival))
+    }
+}
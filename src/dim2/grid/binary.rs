@@ -0,0 +1,230 @@
+use std::fmt;
+
+use super::Grid;
+
+/// first bytes of every encoded grid
+pub const MAGIC: [u8; 4] = *b"XSGR";
+/// format version written by `Grid::to_bytes`
+pub const VERSION: u16 = 1;
+/// largest amount of values `Grid::from_bytes` accepts, see `Grid::from_bytes_with_limit` for other limits
+pub const MAX_VALUES: usize = 1 << 24;
+
+/// reason why bytes could not be decoded into a grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridDecodeError {
+    /// the bytes do not start with `MAGIC`
+    InvalidMagic,
+    UnsupportedVersion(u16),
+    UnknownEncoding(u8),
+    /// the input ended in the middle of the header or the values
+    UnexpectedEnd,
+    /// the codec could not decode a value
+    InvalidValue,
+    /// a run is empty or covers more values than the grid has left
+    InvalidRunLength,
+    /// the grid has more values than the limit of the decoder
+    InvalidSize,
+    /// there are bytes left after the last value
+    TrailingBytes,
+}
+
+impl fmt::Display for GridDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMagic => write!(f, "data is not an encoded grid"),
+            Self::UnsupportedVersion(version) => write!(f, "unsupported grid format version {}", version),
+            Self::UnknownEncoding(encoding) => write!(f, "unknown grid value encoding {}", encoding),
+            Self::UnexpectedEnd => write!(f, "encoded grid ended unexpectedly"),
+            Self::InvalidValue => write!(f, "encoded grid contains an invalid value"),
+            Self::InvalidRunLength => write!(f, "encoded grid contains an invalid run length"),
+            Self::InvalidSize => write!(f, "encoded grid size is too large"),
+            Self::TrailingBytes => write!(f, "encoded grid is followed by unexpected bytes"),
+        }
+    }
+}
+
+impl std::error::Error for GridDecodeError {}
+
+/// how the values are stored after the header
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GridEncoding {
+    /// every value on its own, in row-major order
+    Raw = 0,
+    /// pairs of a repeat count and a value, compact for maps with large areas of the same value
+    RunLength = 1,
+}
+
+impl TryFrom<u8> for GridEncoding {
+    type Error = GridDecodeError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(GridEncoding::Raw),
+            1 => Ok(GridEncoding::RunLength),
+            other => Err(GridDecodeError::UnknownEncoding(other)),
+        }
+    }
+}
+
+/// turns single grid values into bytes and back
+pub trait ValueCodec<T> {
+    fn encode(&self, value: T, output: &mut Vec<u8>);
+
+    /// reads one value from the start of `input` and advances `input` past it
+    fn decode(&self, input: &mut &[u8]) -> Result<T, GridDecodeError>;
+}
+
+/// stores numbers as little endian bytes and `bool` as a single 0 or 1 byte
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LittleEndianCodec;
+
+macro_rules! impl_little_endian_codec {
+    ($($value_type:ty),*) => {
+        $(
+            impl ValueCodec<$value_type> for LittleEndianCodec {
+                fn encode(&self, value: $value_type, output: &mut Vec<u8>) {
+                    output.extend_from_slice(&value.to_le_bytes());
+                }
+
+                fn decode(&self, input: &mut &[u8]) -> Result<$value_type, GridDecodeError> {
+                    Ok(<$value_type>::from_le_bytes(read_bytes(input)?))
+                }
+            }
+        )*
+    };
+}
+
+impl_little_endian_codec!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+impl ValueCodec<bool> for LittleEndianCodec {
+    fn encode(&self, value: bool, output: &mut Vec<u8>) {
+        output.push(value as u8);
+    }
+
+    fn decode(&self, input: &mut &[u8]) -> Result<bool, GridDecodeError> {
+        match read_bytes(input)? {
+            [0] => Ok(false),
+            [1] => Ok(true),
+            _ => Err(GridDecodeError::InvalidValue),
+        }
+    }
+}
+
+/// takes the first `N` bytes of `input` and advances `input` past them, helper for implementing `ValueCodec`
+pub fn read_bytes<const N: usize>(input: &mut &[u8]) -> Result<[u8; N], GridDecodeError> {
+    let (bytes, rest) = input.split_first_chunk::<N>().ok_or(GridDecodeError::UnexpectedEnd)?;
+    *input = rest;
+    Ok(*bytes)
+}
+
+/// LEB128, 7 bits per byte with the highest bit marking that more bytes follow
+fn write_varint(mut value: u64, output: &mut Vec<u8>) {
+    while value >= 0x80 {
+        output.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    output.push(value as u8);
+}
+
+fn read_varint(input: &mut &[u8]) -> Result<u64, GridDecodeError> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let [byte] = read_bytes(input)?;
+        let bits = (byte & 0x7f) as u64;
+        // the last byte only has room for the highest bit of a u64
+        if (bits << shift) >> shift != bits {
+            return Err(GridDecodeError::InvalidRunLength);
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(GridDecodeError::InvalidRunLength)
+}
+
+impl<T> Grid<T>
+where
+    T: Copy + PartialEq,
+{
+    /// encodes the grid as `MAGIC`, `VERSION`, encoding, width and height followed by the values.
+    ///
+    /// the version is a little endian `u16`, the encoding a single byte and the size two little endian `u32`.
+    /// panics if a side of the grid does not fit into a `u32`.
+    pub fn to_bytes<C: ValueCodec<T>>(&self, codec: &C, encoding: GridEncoding) -> Vec<u8> {
+        let width = u32::try_from(self.width()).expect("grid width does not fit into the format");
+        let height = u32::try_from(self.height()).expect("grid height does not fit into the format");
+
+        let mut output = Vec::with_capacity(15 + self.len());
+        output.extend_from_slice(&MAGIC);
+        output.extend_from_slice(&VERSION.to_le_bytes());
+        output.push(encoding as u8);
+        output.extend_from_slice(&width.to_le_bytes());
+        output.extend_from_slice(&height.to_le_bytes());
+
+        match encoding {
+            GridEncoding::Raw => {
+                for &value in self.iter() {
+                    codec.encode(value, &mut output);
+                }
+            }
+            GridEncoding::RunLength => {
+                for run in self.values.chunk_by(|a, b| a == b) {
+                    write_varint(run.len() as u64, &mut output);
+                    codec.encode(run[0], &mut output);
+                }
+            }
+        }
+        output
+    }
+
+    /// decodes a grid written by `to_bytes` with the same codec, grids with more than `MAX_VALUES` values are rejected
+    pub fn from_bytes<C: ValueCodec<T>>(bytes: &[u8], codec: &C) -> Result<Grid<T>, GridDecodeError> {
+        Self::from_bytes_with_limit(bytes, codec, MAX_VALUES)
+    }
+
+    /// like `from_bytes`, but rejects grids with more than `max_values` values.
+    ///
+    /// run length encoded input can describe huge grids in a few bytes, the limit keeps untrusted input from
+    /// allocating more memory than expected.
+    pub fn from_bytes_with_limit<C: ValueCodec<T>>(bytes: &[u8], codec: &C, max_values: usize) -> Result<Grid<T>, GridDecodeError> {
+        let mut input = bytes;
+        if read_bytes::<4>(&mut input)? != MAGIC {
+            return Err(GridDecodeError::InvalidMagic);
+        }
+        let version = u16::from_le_bytes(read_bytes(&mut input)?);
+        if version != VERSION {
+            return Err(GridDecodeError::UnsupportedVersion(version));
+        }
+        let [encoding] = read_bytes(&mut input)?;
+        let encoding = GridEncoding::try_from(encoding)?;
+        let width = u32::from_le_bytes(read_bytes(&mut input)?) as usize;
+        let height = u32::from_le_bytes(read_bytes(&mut input)?) as usize;
+        let len = width.checked_mul(height).filter(|&len| len <= max_values).ok_or(GridDecodeError::InvalidSize)?;
+
+        // the capacity is limited by the input so a forged size can not reserve huge amounts of memory up front
+        let mut values = Vec::with_capacity(len.min(input.len()));
+        match encoding {
+            GridEncoding::Raw => {
+                for _ in 0..len {
+                    values.push(codec.decode(&mut input)?);
+                }
+            }
+            GridEncoding::RunLength => {
+                while values.len() < len {
+                    let run = read_varint(&mut input)?;
+                    if run == 0 || run > (len - values.len()) as u64 {
+                        return Err(GridDecodeError::InvalidRunLength);
+                    }
+                    let value = codec.decode(&mut input)?;
+                    values.extend(std::iter::repeat_n(value, run as usize));
+                }
+            }
+        }
+
+        if !input.is_empty() {
+            return Err(GridDecodeError::TrailingBytes);
+        }
+        Ok(Grid::new(width, height, values.into_boxed_slice()))
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{dim2::vec, ScreenView};
+
+use super::{GridRect, Position, RectSize, ScreenTranslation};
+
+/// looks at a point of the unmoved screen space with a zoom factor and rotation.
+///
+/// the camera center is displayed in the middle of the `ScreenView`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Camera {
+    pub center_x: f32,
+    pub center_y: f32,
+    /// values above 1 enlarge everything on screen
+    pub zoom: f32,
+    /// counter clockwise rotation in radians
+    pub rotation: f32,
+}
+
+impl Camera {
+    pub const fn new(center_x: f32, center_y: f32) -> Self {
+        Camera {
+            center_x,
+            center_y,
+            zoom: 1.,
+            rotation: 0.,
+        }
+    }
+
+    /// camera that shows the screen view without any transformation
+    pub fn centered_on(screen_view: ScreenView) -> Self {
+        Camera::new(
+            screen_view.offset_x + screen_view.width / 2.,
+            screen_view.offset_y + screen_view.height / 2.,
+        )
+    }
+
+    /// moves the camera center by the given distance on screen, independent of zoom and rotation
+    pub fn pan(&mut self, screen_dx: f32, screen_dy: f32) {
+        let (dx, dy) = vec::rotate(screen_dx / self.zoom, screen_dy / self.zoom, -self.rotation);
+        self.center_x += dx;
+        self.center_y += dy;
+    }
+
+    pub fn zoom_by(&mut self, factor: f32) {
+        self.zoom *= factor;
+    }
+
+    pub fn rotate(&mut self, angle: f32) {
+        self.rotation += angle;
+    }
+
+    /// transforms a point of the unmoved screen space onto the screen
+    pub fn world_to_screen(&self, screen_view: &ScreenView, world_x: f32, world_y: f32) -> (f32, f32) {
+        let (x, y) = vec::rotate((world_x - self.center_x) * self.zoom, (world_y - self.center_y) * self.zoom, self.rotation);
+        (
+            screen_view.offset_x + screen_view.width / 2. + x,
+            screen_view.offset_y + screen_view.height / 2. + y,
+        )
+    }
+
+    /// transforms a point on the screen back into the unmoved screen space
+    pub fn screen_to_world(&self, screen_view: &ScreenView, screen_x: f32, screen_y: f32) -> (f32, f32) {
+        let (x, y) = vec::rotate(
+            screen_x - screen_view.offset_x - screen_view.width / 2.,
+            screen_y - screen_view.offset_y - screen_view.height / 2.,
+            -self.rotation,
+        );
+        (self.center_x + x / self.zoom, self.center_y + y / self.zoom)
+    }
+}
+
+/// a `ScreenTranslation` viewed through a `Camera`
+#[derive(Clone, Copy)]
+pub struct CameraTranslation {
+    pub translation: ScreenTranslation,
+    pub camera: Camera,
+}
+
+impl CameraTranslation {
+    pub fn new(translation: ScreenTranslation, camera: Camera) -> Self {
+        CameraTranslation { translation, camera }
+    }
+
+    /// camera that initially shows the translation unmodified
+    pub fn centered(translation: ScreenTranslation) -> Self {
+        CameraTranslation::new(translation, Camera::centered_on(translation.screen_view()))
+    }
+
+    pub fn get_grid_position(&self, screen_x: f32, screen_y: f32) -> Option<Position> {
+        let screen_view = self.translation.screen_view();
+        if !(screen_view.offset_x..screen_view.offset_x + screen_view.width).contains(&screen_x)
+            || !(screen_view.offset_y..screen_view.offset_y + screen_view.height).contains(&screen_y)
+        {
+            return None;
+        }
+
+        let (world_x, world_y) = self.camera.screen_to_world(&screen_view, screen_x, screen_y);
+        self.translation.get_grid_position(world_x, world_y)
+    }
+
+    pub fn block_center_to_screen_position(&self, x: i64, y: i64) -> (f32, f32) {
+        let (world_x, world_y) = self.translation.block_center_to_screen_position(x, y);
+        self.camera.world_to_screen(&self.translation.screen_view(), world_x, world_y)
+    }
+
+    /// smallest grid region containing every tile that is at least partially visible.
+    ///
+    /// when the camera is rotated, tiles in the corners of the region might not be on screen.
+    pub fn visible_region(&self) -> Option<GridRect> {
+        let screen_view = self.translation.screen_view();
+        let corners = [
+            (screen_view.offset_x, screen_view.offset_y),
+            (screen_view.offset_x + screen_view.width, screen_view.offset_y),
+            (screen_view.offset_x, screen_view.offset_y + screen_view.height),
+            (screen_view.offset_x + screen_view.width, screen_view.offset_y + screen_view.height),
+        ];
+
+        let mut min = (f32::INFINITY, f32::INFINITY);
+        let mut max = (f32::NEG_INFINITY, f32::NEG_INFINITY);
+        for (screen_x, screen_y) in corners {
+            let (world_x, world_y) = self.camera.screen_to_world(&screen_view, screen_x, screen_y);
+            let (grid_x, grid_y) = self.translation.screen_to_grid_coordinates(world_x, world_y);
+            min = (min.0.min(grid_x), min.1.min(grid_y));
+            max = (max.0.max(grid_x), max.1.max(grid_y));
+        }
+
+        let grid_size = self.translation.grid_size();
+        let left = (min.0.floor() as i64).max(0);
+        let top = (min.1.floor() as i64).max(0);
+        let right = (max.0.ceil() as i64).min(grid_size.width as i64);
+        let bottom = (max.1.ceil() as i64).min(grid_size.height as i64);
+
+        if left >= right || top >= bottom {
+            return None;
+        }
+
+        Some(GridRect::new(
+            Position::new(left, top),
+            RectSize::new((right - left) as usize, (bottom - top) as usize),
+        ))
+    }
+
+    /// all positions inside of `visible_region`
+    pub fn visible_positions(&self) -> Vec<Position> {
+        match self.visible_region() {
+            Some(region) => region.iter().collect(),
+            None => vec![],
+        }
+    }
+}
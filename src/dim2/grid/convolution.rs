@@ -0,0 +1,74 @@
+use super::{patterns::PatternPositions, Grid, Position, RectSize, WrapMode};
+
+/// how pattern positions outside of the grid are treated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeHandling {
+    /// uses the value of the closest position inside the grid
+    Clamp,
+    /// continues on the opposite side of the grid on the axes of the wrap mode, skips positions outside on the other axes.
+    ///
+    /// `Wrap(WrapMode::None)` behaves like `Skip`.
+    Wrap(WrapMode),
+    /// leaves positions outside of the grid out of the neighborhood
+    Skip,
+}
+
+impl EdgeHandling {
+    /// position inside a grid of the given size that stands in for `position`, `None` if it is skipped
+    pub fn resolve(&self, position: Position, width: usize, height: usize) -> Option<Position> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        let size = RectSize::new(width, height);
+        let (width, height) = (width as i64, height as i64);
+        match self {
+            EdgeHandling::Clamp => Some(Position::new(position.x.clamp(0, width - 1), position.y.clamp(0, height - 1))),
+            EdgeHandling::Wrap(wrap_mode) => wrap_mode.wrap(position, size),
+            EdgeHandling::Skip => {
+                if (0..width).contains(&position.x) && (0..height).contains(&position.y) {
+                    Some(position)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Copy,
+{
+    /// values of every pattern position around `center`, see `EdgeHandling` for positions outside of the grid
+    pub fn get_neighborhood(&self, center: Position, pattern: &dyn PatternPositions, edge_handling: EdgeHandling) -> Box<[T]> {
+        pattern
+            .get_pattern_positions(center)
+            .iter()
+            .filter_map(|&pos| edge_handling.resolve(pos, self.width(), self.height()))
+            .map(|pos| self[pos])
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    }
+
+    /// creates a new grid by calling `f` with every value and the values of its pattern neighborhood
+    pub fn convolve<U, F>(&self, pattern: &dyn PatternPositions, edge_handling: EdgeHandling, mut f: F) -> Grid<U>
+    where
+        U: Copy,
+        F: FnMut(T, &[T]) -> U,
+    {
+        // reused for every cell instead of collecting a new neighborhood each time
+        let mut neighborhood = Vec::new();
+        self.map_with_position(|pos, value| {
+            neighborhood.clear();
+            neighborhood.extend(
+                pattern
+                    .get_pattern_positions(pos)
+                    .iter()
+                    .filter_map(|&pos| edge_handling.resolve(pos, self.width(), self.height()))
+                    .map(|pos| self[pos]),
+            );
+            f(value, &neighborhood)
+        })
+    }
+}
@@ -0,0 +1,78 @@
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// signed integer type used for the components of a `Position`.
+///
+/// smaller types save memory for large position lists, but every grid they address has to fit into their range.
+pub trait Coordinate:
+    Copy
+    + Default
+    + Debug
+    + Ord
+    + Hash
+    + Send
+    + Sync
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + 'static
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn to_i64(self) -> i64;
+
+    /// panics if the value is out of range, see `try_from_i64` for a checked conversion
+    fn from_i64(value: i64) -> Self {
+        Self::try_from_i64(value).expect("coordinate out of range")
+    }
+
+    fn try_from_i64(value: i64) -> Option<Self>;
+
+    /// panics if the value is out of range
+    fn from_usize(value: usize) -> Self {
+        Self::from_i64(value as i64)
+    }
+
+    fn abs(self) -> Self;
+
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn to_i64(self) -> i64 {
+                    self as i64
+                }
+
+                fn try_from_i64(value: i64) -> Option<Self> {
+                    <$t>::try_from(value).ok()
+                }
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn signum(self) -> Self {
+                    <$t>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_coordinate!(i16, i32, i64);
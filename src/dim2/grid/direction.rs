@@ -0,0 +1,106 @@
+use super::{Coordinate, Position};
+
+/// direction on the grid, `Up` points towards decreasing `y` like rows on screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// the 4 straight directions, clockwise starting at `Up`
+    pub const CARDINAL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// the 4 diagonal directions, clockwise starting at `UpRight`
+    pub const DIAGONAL: [Direction; 4] = [Direction::UpRight, Direction::DownRight, Direction::DownLeft, Direction::UpLeft];
+
+    /// all 8 directions, clockwise starting at `Up`
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    pub const fn offset(self) -> Position {
+        match self {
+            Direction::Up => Position::new(0, -1),
+            Direction::UpRight => Position::new(1, -1),
+            Direction::Right => Position::new(1, 0),
+            Direction::DownRight => Position::new(1, 1),
+            Direction::Down => Position::new(0, 1),
+            Direction::DownLeft => Position::new(-1, 1),
+            Direction::Left => Position::new(-1, 0),
+            Direction::UpLeft => Position::new(-1, -1),
+        }
+    }
+
+    /// direction pointing along `delta`, e.g. `Right` for `(5, 0)` and `DownRight` for `(2, 2)`.
+    ///
+    /// `None` for a zero delta and for deltas that are neither straight nor exactly diagonal, like `(5, 1)`.
+    pub fn from_delta<C: Coordinate>(delta: Position<C>) -> Option<Direction> {
+        let (x, y) = (delta.x.to_i64(), delta.y.to_i64());
+        if x != 0 && y != 0 && x.unsigned_abs() != y.unsigned_abs() {
+            return None;
+        }
+
+        match (x.signum(), y.signum()) {
+            (0, -1) => Some(Direction::Up),
+            (1, -1) => Some(Direction::UpRight),
+            (1, 0) => Some(Direction::Right),
+            (1, 1) => Some(Direction::DownRight),
+            (0, 1) => Some(Direction::Down),
+            (-1, 1) => Some(Direction::DownLeft),
+            (-1, 0) => Some(Direction::Left),
+            (-1, -1) => Some(Direction::UpLeft),
+            _ => None,
+        }
+    }
+
+    pub const fn is_diagonal(self) -> bool {
+        matches!(self, Direction::UpRight | Direction::DownRight | Direction::DownLeft | Direction::UpLeft)
+    }
+
+    pub const fn opposite(self) -> Direction {
+        self.rotate_clockwise_steps(4)
+    }
+
+    /// rotates by 45 degrees clockwise
+    pub const fn rotate_clockwise(self) -> Direction {
+        self.rotate_clockwise_steps(1)
+    }
+
+    /// rotates by 45 degrees counter clockwise
+    pub const fn rotate_counter_clockwise(self) -> Direction {
+        self.rotate_clockwise_steps(7)
+    }
+
+    pub const fn rotate_clockwise_90(self) -> Direction {
+        self.rotate_clockwise_steps(2)
+    }
+
+    pub const fn rotate_counter_clockwise_90(self) -> Direction {
+        self.rotate_clockwise_steps(6)
+    }
+
+    /// rotates clockwise in steps of 45 degrees
+    pub const fn rotate_clockwise_steps(self, steps: usize) -> Direction {
+        Direction::ALL[(self as usize + steps % 8) % 8]
+    }
+}
+
+impl From<Direction> for Position {
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
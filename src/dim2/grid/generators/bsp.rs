@@ -0,0 +1,86 @@
+use crate::dim2::grid::{Grid, GridRect, Position, RectSize};
+use crate::random::Rng;
+
+use super::{carve_corridor, carve_rect, connect_regions, filled_with_walls, inner_rect, Tile};
+
+/// splits the map recursively into two parts until the parts get too small, places a room into every part
+/// and connects the rooms of sibling parts with corridors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BspDungeon {
+    pub size: RectSize,
+    /// parts smaller than twice this size are not split any further
+    pub min_leaf_size: usize,
+    /// rooms keep a wall to the border of their part, so they are at most `min_leaf_size - 2` wide in small parts
+    pub min_room_size: usize,
+}
+
+impl BspDungeon {
+    pub const fn new(size: RectSize) -> Self {
+        BspDungeon {
+            size,
+            min_leaf_size: 8,
+            min_room_size: 3,
+        }
+    }
+
+    pub fn generate(&self, rng: &mut Rng) -> Grid<Tile> {
+        self.generate_with_rooms(rng).0
+    }
+
+    /// generates the map and returns the area of every room
+    pub fn generate_with_rooms(&self, rng: &mut Rng) -> (Grid<Tile>, Vec<GridRect>) {
+        assert!(self.min_room_size > 0, "rooms need at least one tile");
+        assert!(self.min_leaf_size >= self.min_room_size + 2, "leaves have to fit a room and its walls");
+
+        let mut grid = filled_with_walls(self.size);
+        let mut rooms = Vec::new();
+        let area = inner_rect(self.size);
+        if area.size.width >= self.min_leaf_size && area.size.height >= self.min_leaf_size {
+            self.split(area, &mut grid, &mut rooms, rng);
+            connect_regions(&mut grid, rng);
+        }
+        (grid, rooms)
+    }
+
+    /// returns the center of one of the rooms placed inside of `leaf`
+    fn split(&self, leaf: GridRect, grid: &mut Grid<Tile>, rooms: &mut Vec<GridRect>, rng: &mut Rng) -> Position {
+        let can_split_horizontally = leaf.size.width >= 2 * self.min_leaf_size;
+        let can_split_vertically = leaf.size.height >= 2 * self.min_leaf_size;
+
+        let split_horizontally = match (can_split_horizontally, can_split_vertically) {
+            (false, false) => return self.place_room(leaf, grid, rooms, rng),
+            (true, true) => rng.chance(leaf.size.width as f64 / (leaf.size.width + leaf.size.height) as f64),
+            (horizontally, _) => horizontally,
+        };
+
+        let (first, second) = if split_horizontally {
+            leaf.split_horizontally(rng.range_usize(self.min_leaf_size..leaf.size.width - self.min_leaf_size + 1))
+        } else {
+            leaf.split_vertically(rng.range_usize(self.min_leaf_size..leaf.size.height - self.min_leaf_size + 1))
+        };
+
+        let first_room = self.split(first, grid, rooms, rng);
+        let second_room = self.split(second, grid, rooms, rng);
+        carve_corridor(grid, first_room, second_room, split_horizontally);
+        if rng.chance(0.5) {
+            first_room
+        } else {
+            second_room
+        }
+    }
+
+    fn place_room(&self, leaf: GridRect, grid: &mut Grid<Tile>, rooms: &mut Vec<GridRect>, rng: &mut Rng) -> Position {
+        let space = leaf.shrink(1);
+        let width = rng.range_usize(self.min_room_size.min(space.size.width)..space.size.width + 1);
+        let height = rng.range_usize(self.min_room_size.min(space.size.height)..space.size.height + 1);
+        let offset = Position::new(
+            space.left() + rng.range_usize(0..space.size.width - width + 1) as i64,
+            space.top() + rng.range_usize(0..space.size.height - height + 1) as i64,
+        );
+
+        let room = GridRect::new(offset, RectSize::new(width, height));
+        carve_rect(grid, room);
+        rooms.push(room);
+        room.center()
+    }
+}
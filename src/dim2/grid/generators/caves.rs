@@ -0,0 +1,52 @@
+use crate::dim2::grid::{patterns::surrounding_pattern, CellularAutomaton, Grid, GridRect, LifeRule, RectSize, WrapMode};
+use crate::random::Rng;
+
+use super::{connect_regions, inner_rect, Tile};
+
+/// fills the map with random walls and smooths them into caves with a cellular automaton, walls being the living cells.
+///
+/// separate caves get connected with corridors afterwards.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CaveDungeon {
+    pub size: RectSize,
+    /// chance of every tile to start as a wall
+    pub wall_probability: f64,
+    /// amount of automaton steps, more steps give smoother caves
+    pub steps: usize,
+    pub rule: LifeRule,
+}
+
+impl CaveDungeon {
+    pub const fn new(size: RectSize) -> Self {
+        CaveDungeon {
+            size,
+            wall_probability: 0.45,
+            steps: 5,
+            rule: LifeRule::CAVES,
+        }
+    }
+
+    pub fn generate(&self, rng: &mut Rng) -> Grid<Tile> {
+        let area = inner_rect(self.size);
+        let walls: Vec<_> = self.size.iter().map(|pos| !area.contains(pos) || rng.chance(self.wall_probability)).collect();
+        let walls = Grid::new(self.size.width, self.size.height, walls.into_boxed_slice());
+
+        let mut automaton = CellularAutomaton::new(walls, &*surrounding_pattern(1), WrapMode::None);
+        for _ in 0..self.steps {
+            automaton.step_life(&self.rule);
+            set_border_walls(automaton.grid_mut(), area);
+        }
+
+        let mut grid = automaton.grid().map(|wall| if wall { Tile::Wall } else { Tile::Floor });
+        connect_regions(&mut grid, rng);
+        grid
+    }
+}
+
+fn set_border_walls(walls: &mut Grid<bool>, area: GridRect) {
+    for (pos, wall) in walls.iter_mut_with_position() {
+        if !area.contains(pos) {
+            *wall = true;
+        }
+    }
+}
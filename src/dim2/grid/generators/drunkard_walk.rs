@@ -0,0 +1,57 @@
+use crate::dim2::grid::{Direction, Grid, RectSize};
+use crate::random::Rng;
+
+use super::{filled_with_walls, inner_rect, Tile};
+
+/// carves floor by walking randomly from the center of the map until enough of the map is floor.
+///
+/// the walk never leaves the carved area, so the floor is always connected.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrunkardWalk {
+    pub size: RectSize,
+    /// share of the tiles inside of the outer walls that should become floor
+    pub floor_ratio: f64,
+    /// stops the walk even if not enough floor got carved
+    pub max_steps: usize,
+}
+
+impl DrunkardWalk {
+    pub const fn new(size: RectSize) -> Self {
+        DrunkardWalk {
+            size,
+            floor_ratio: 0.4,
+            max_steps: 100_000,
+        }
+    }
+
+    pub fn generate(&self, rng: &mut Rng) -> Grid<Tile> {
+        let mut grid = filled_with_walls(self.size);
+        let area = inner_rect(self.size);
+        if area.is_empty() {
+            return grid;
+        }
+
+        let target = ((area.size.len() as f64 * self.floor_ratio).ceil() as usize).clamp(1, area.size.len());
+        let mut position = area.center();
+        grid[position] = Tile::Floor;
+        let mut floor_count = 1;
+
+        for _ in 0..self.max_steps {
+            if floor_count >= target {
+                break;
+            }
+
+            let next = position.neighbor(Direction::CARDINAL[rng.range_usize(0..Direction::CARDINAL.len())]);
+            if !area.contains(next) {
+                continue;
+            }
+
+            position = next;
+            if grid[position] == Tile::Wall {
+                grid[position] = Tile::Floor;
+                floor_count += 1;
+            }
+        }
+        grid
+    }
+}
@@ -0,0 +1,111 @@
+pub mod bsp;
+pub use bsp::BspDungeon;
+
+pub mod caves;
+pub use caves::CaveDungeon;
+
+pub mod drunkard_walk;
+pub use drunkard_walk::DrunkardWalk;
+
+pub mod rooms;
+pub use rooms::RoomsAndCorridors;
+
+pub mod wave_function_collapse;
+pub use wave_function_collapse::{AdjacencyRules, SampleTransform, SampleTransforms, WaveFunctionCollapse};
+
+use std::collections::VecDeque;
+
+use crate::random::Rng;
+
+use super::{pathing::path_exists, Grid, GridRect, Position, RectSize};
+
+/// content of a generated map tile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Tile {
+    #[default]
+    Wall,
+    Floor,
+}
+
+impl Tile {
+    pub fn is_floor(self) -> bool {
+        self == Tile::Floor
+    }
+}
+
+/// grid of the given size with every tile set to `Tile::Wall`
+pub fn filled_with_walls(size: RectSize) -> Grid<Tile> {
+    Grid::new(size.width, size.height, vec![Tile::Wall; size.len()].into_boxed_slice())
+}
+
+/// turns every tile of `rect` inside the grid into floor
+pub fn carve_rect(grid: &mut Grid<Tile>, rect: GridRect) {
+    for pos in rect.iter() {
+        if let Some(tile) = grid.get_mut(pos) {
+            *tile = Tile::Floor;
+        }
+    }
+}
+
+/// carves an L shaped corridor between two positions, going horizontally first if `horizontal_first` is set
+pub fn carve_corridor(grid: &mut Grid<Tile>, from: Position, to: Position, horizontal_first: bool) {
+    let corner = if horizontal_first { Position::new(to.x, from.y) } else { Position::new(from.x, to.y) };
+    carve_rect(grid, GridRect::from_corners(from, corner));
+    carve_rect(grid, GridRect::from_corners(corner, to));
+}
+
+/// groups of floor tiles that are connected by horizontal and vertical steps, largest group first
+pub fn floor_regions(grid: &Grid<Tile>) -> Vec<Vec<Position>> {
+    let mut visited = grid.map(|_| false);
+    let mut regions = Vec::new();
+
+    for (start, tile) in grid.iter_with_position() {
+        if !tile.is_floor() || visited[start] {
+            continue;
+        }
+
+        visited[start] = true;
+        let mut region = Vec::new();
+        let mut queue = VecDeque::from([start]);
+        while let Some(pos) = queue.pop_front() {
+            region.push(pos);
+            for neighbor in pos.neighbors4() {
+                if grid.get(neighbor).is_some_and(Tile::is_floor) && !visited[neighbor] {
+                    visited[neighbor] = true;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        regions.push(region);
+    }
+
+    regions.sort_by_key(|region| std::cmp::Reverse(region.len()));
+    regions
+}
+
+/// whether every floor tile can be reached from every other floor tile
+pub fn is_connected(grid: &Grid<Tile>) -> bool {
+    floor_regions(grid).len() <= 1
+}
+
+/// carves corridors from every floor region to the largest one until all floor tiles are reachable from each other
+pub fn connect_regions(grid: &mut Grid<Tile>, rng: &mut Rng) {
+    let regions = floor_regions(grid);
+    let Some((main_region, other_regions)) = regions.split_first() else {
+        return;
+    };
+
+    for region in other_regions {
+        let from = region[rng.range_usize(0..region.len())];
+        let to = main_region[rng.range_usize(0..main_region.len())];
+        // an earlier corridor might already lead through this region
+        if !path_exists(grid, from, to, &Tile::is_floor) {
+            carve_corridor(grid, from, to, rng.chance(0.5));
+        }
+    }
+}
+
+/// positions that are not on the outermost ring of the grid, generators keep those as walls
+fn inner_rect(size: RectSize) -> GridRect {
+    GridRect::from_size(size).shrink(1)
+}
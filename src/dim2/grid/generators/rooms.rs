@@ -0,0 +1,74 @@
+use crate::dim2::grid::{Grid, GridRect, Position, RectSize};
+use crate::random::Rng;
+
+use super::{carve_corridor, carve_rect, connect_regions, filled_with_walls, inner_rect, Tile};
+
+const PLACEMENT_ATTEMPTS_PER_ROOM: usize = 10;
+
+/// places rooms of random size at random positions without overlapping and connects each room to the one placed before
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoomsAndCorridors {
+    pub size: RectSize,
+    /// fewer rooms are placed if there is not enough space left
+    pub max_rooms: usize,
+    pub min_room_size: usize,
+    pub max_room_size: usize,
+}
+
+impl RoomsAndCorridors {
+    pub const fn new(size: RectSize) -> Self {
+        RoomsAndCorridors {
+            size,
+            max_rooms: 12,
+            min_room_size: 4,
+            max_room_size: 10,
+        }
+    }
+
+    pub fn generate(&self, rng: &mut Rng) -> Grid<Tile> {
+        self.generate_with_rooms(rng).0
+    }
+
+    /// generates the map and returns the area of every room in the order they got placed
+    pub fn generate_with_rooms(&self, rng: &mut Rng) -> (Grid<Tile>, Vec<GridRect>) {
+        assert!(self.min_room_size > 0 && self.min_room_size <= self.max_room_size, "invalid room size range");
+
+        let mut grid = filled_with_walls(self.size);
+        let mut rooms: Vec<GridRect> = Vec::new();
+        let area = inner_rect(self.size);
+        let max_width = self.max_room_size.min(area.size.width);
+        let max_height = self.max_room_size.min(area.size.height);
+        if max_width < self.min_room_size || max_height < self.min_room_size {
+            return (grid, rooms);
+        }
+
+        for _ in 0..self.max_rooms * PLACEMENT_ATTEMPTS_PER_ROOM {
+            if rooms.len() >= self.max_rooms {
+                break;
+            }
+
+            let size = RectSize::new(
+                rng.range_usize(self.min_room_size..max_width + 1),
+                rng.range_usize(self.min_room_size..max_height + 1),
+            );
+            let offset = Position::new(
+                area.left() + rng.range_usize(0..area.size.width - size.width + 1) as i64,
+                area.top() + rng.range_usize(0..area.size.height - size.height + 1) as i64,
+            );
+            let room = GridRect::new(offset, size);
+            // keeps at least one wall between rooms
+            if rooms.iter().any(|other| other.expand(1).intersects(&room)) {
+                continue;
+            }
+
+            carve_rect(&mut grid, room);
+            if let Some(previous) = rooms.last() {
+                carve_corridor(&mut grid, previous.center(), room.center(), rng.chance(0.5));
+            }
+            rooms.push(room);
+        }
+
+        connect_regions(&mut grid, rng);
+        (grid, rooms)
+    }
+}
@@ -0,0 +1,360 @@
+use std::fmt;
+
+use crate::dim2::grid::{to_grid_index, to_grid_position, Direction, Grid, RectSize, WrapMode};
+use crate::random::Rng;
+
+/// additional variants of a sample that adjacency rules are learned from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SampleTransforms {
+    /// only the sample as it is
+    #[default]
+    None,
+    /// the sample rotated by 90, 180 and 270 degrees
+    Rotations,
+    /// the sample mirrored horizontally and vertically
+    Reflections,
+    /// every rotation and reflection
+    All,
+}
+
+impl SampleTransforms {
+    /// every transform applied to the sample, starting with the unchanged sample
+    pub fn variants(self) -> Vec<SampleTransform> {
+        let transform = |mirrored, quarter_turns| SampleTransform { mirrored, quarter_turns };
+        match self {
+            SampleTransforms::None => vec![transform(false, 0)],
+            SampleTransforms::Rotations => (0..4).map(|turns| transform(false, turns)).collect(),
+            // a vertical mirror is a horizontal mirror rotated by 180 degrees
+            SampleTransforms::Reflections => vec![transform(false, 0), transform(true, 0), transform(true, 2), transform(false, 2)],
+            SampleTransforms::All => [false, true]
+                .into_iter()
+                .flat_map(|mirrored| (0..4).map(move |turns| transform(mirrored, turns)))
+                .collect(),
+        }
+    }
+}
+
+/// single variant of a sample, mirrored horizontally first and rotated clockwise afterwards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SampleTransform {
+    pub mirrored: bool,
+    /// clockwise rotation in steps of 90 degrees
+    pub quarter_turns: u8,
+}
+
+impl SampleTransform {
+    /// direction between two tiles of the sample after transforming it
+    pub fn apply(self, direction: Direction) -> Direction {
+        let direction = if self.mirrored { mirror_horizontally(direction) } else { direction };
+        direction.rotate_clockwise_steps(2 * self.quarter_turns as usize)
+    }
+}
+
+/// tiles together with their weights and which tiles may be placed next to each other
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdjacencyRules<T> {
+    tiles: Vec<T>,
+    weights: Vec<f64>,
+    /// `allowed[tile][direction]` contains a flag for every tile that may be next to `tile` in `direction`
+    allowed: Vec<[Vec<bool>; 4]>,
+}
+
+impl<T> AdjacencyRules<T>
+where
+    T: Copy + PartialEq,
+{
+    pub fn new() -> Self {
+        AdjacencyRules {
+            tiles: Vec::new(),
+            weights: Vec::new(),
+            allowed: Vec::new(),
+        }
+    }
+
+    /// learns the tiles, their weights and adjacencies from every pair of horizontally and vertically neighboring values
+    /// in every variant of the sample.
+    ///
+    /// `transform_tile` turns a tile into how it looks in a transformed sample, e.g. a horizontal pipe into a vertical
+    /// one for a quarter turn. tiles that look the same in every orientation can be returned unchanged. the weight of
+    /// a tile is the amount of times it appears in all variants.
+    pub fn from_sample<F>(sample: &Grid<T>, transforms: SampleTransforms, transform_tile: F) -> Self
+    where
+        F: Fn(T, SampleTransform) -> T,
+    {
+        let variants = transforms.variants();
+        let mut rules = AdjacencyRules::new();
+        for &tile in sample.iter() {
+            for &variant in &variants {
+                let tile = transform_tile(tile, variant);
+                let index = rules.index_of(tile).unwrap_or_else(|| rules.add_tile(tile, 0.));
+                rules.weights[index] += 1.;
+            }
+        }
+
+        for (pos, &tile) in sample.iter_with_position() {
+            for direction in [Direction::Right, Direction::Down] {
+                if let Some(neighbor) = sample.get(pos.neighbor(direction)) {
+                    for &variant in &variants {
+                        rules.allow(transform_tile(tile, variant), variant.apply(direction), transform_tile(neighbor, variant));
+                    }
+                }
+            }
+        }
+        rules
+    }
+
+    /// adds a tile that is not allowed next to anything yet, only changes the weight if the tile is already known.
+    ///
+    /// returns the index of the tile. tiles with a higher weight get chosen more often.
+    pub fn add_tile(&mut self, tile: T, weight: f64) -> usize {
+        if let Some(index) = self.index_of(tile) {
+            self.weights[index] = weight;
+            return index;
+        }
+
+        self.tiles.push(tile);
+        self.weights.push(weight);
+        for allowed in self.allowed.iter_mut().flat_map(|allowed| allowed.iter_mut()) {
+            allowed.push(false);
+        }
+        let tile_count = self.tiles.len();
+        self.allowed.push(std::array::from_fn(|_| vec![false; tile_count]));
+        tile_count - 1
+    }
+
+    /// allows `neighbor` to be in `direction` of `tile`, and thus `tile` in the opposite direction of `neighbor`.
+    ///
+    /// unknown tiles are added with a weight of 1. panics for diagonal directions.
+    pub fn allow(&mut self, tile: T, direction: Direction, neighbor: T) {
+        assert!(!direction.is_diagonal(), "adjacency rules only cover straight directions");
+        let tile = self.index_of(tile).unwrap_or_else(|| self.add_tile(tile, 1.));
+        let neighbor = self.index_of(neighbor).unwrap_or_else(|| self.add_tile(neighbor, 1.));
+        self.allowed[tile][cardinal_index(direction)][neighbor] = true;
+        self.allowed[neighbor][cardinal_index(direction.opposite())][tile] = true;
+    }
+
+    pub fn is_allowed(&self, tile: T, direction: Direction, neighbor: T) -> bool {
+        match (self.index_of(tile), self.index_of(neighbor)) {
+            (Some(tile), Some(neighbor)) if !direction.is_diagonal() => self.allowed[tile][cardinal_index(direction)][neighbor],
+            _ => false,
+        }
+    }
+
+    pub fn tiles(&self) -> &[T] {
+        &self.tiles
+    }
+
+    /// weight of every tile, in the same order as `tiles`
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    fn index_of(&self, tile: T) -> Option<usize> {
+        self.tiles.iter().position(|&known| known == tile)
+    }
+}
+
+impl<T> Default for AdjacencyRules<T>
+where
+    T: Copy + PartialEq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// index of a straight direction inside of `Direction::CARDINAL`
+fn cardinal_index(direction: Direction) -> usize {
+    direction as usize / 2
+}
+
+fn mirror_horizontally(direction: Direction) -> Direction {
+    match direction {
+        Direction::Left => Direction::Right,
+        Direction::Right => Direction::Left,
+        other => other,
+    }
+}
+
+
+/// reason why no grid could be generated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaveFunctionCollapseError {
+    /// the rules do not contain any tile with a positive weight
+    NoTiles,
+    /// every attempt ran into a position where no tile fits, even after `max_backtracks` undone choices
+    Contradiction,
+}
+
+impl fmt::Display for WaveFunctionCollapseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoTiles => write!(f, "adjacency rules do not contain any tile to place"),
+            Self::Contradiction => write!(f, "no tile arrangement satisfying the adjacency rules was found"),
+        }
+    }
+}
+
+impl std::error::Error for WaveFunctionCollapseError {}
+
+/// fills a grid with tiles so every pair of neighboring tiles is allowed by the adjacency rules.
+///
+/// repeatedly places a weighted random tile at the position with the fewest remaining options and removes the
+/// options of its neighbors that are no longer allowed. choices that lead to a position without options are undone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WaveFunctionCollapse {
+    pub size: RectSize,
+    /// wrapping axes also have to match across the border, e.g. for tileable textures
+    pub wrap_mode: WrapMode,
+    /// amount of choices that can be undone before giving up
+    pub max_backtracks: usize,
+}
+
+/// `tile` chosen at `position`, undone by restoring the options removed since the trail had `trail_len` entries
+struct Choice {
+    trail_len: usize,
+    position: usize,
+    tile: usize,
+}
+
+impl WaveFunctionCollapse {
+    pub const fn new(size: RectSize) -> Self {
+        WaveFunctionCollapse {
+            size,
+            wrap_mode: WrapMode::None,
+            max_backtracks: 1000,
+        }
+    }
+
+    pub fn generate<T>(&self, rules: &AdjacencyRules<T>, rng: &mut Rng) -> Result<Grid<T>, WaveFunctionCollapseError>
+    where
+        T: Copy + PartialEq,
+    {
+        let tile_count = rules.tiles.len();
+        if !rules.weights.iter().any(|&weight| weight > 0.) {
+            return Err(WaveFunctionCollapseError::NoTiles);
+        }
+
+        // options of every position, `tile_count` flags per position
+        let mut options: Vec<bool> = (0..self.size.len()).flat_map(|_| rules.weights.iter().map(|&weight| weight > 0.)).collect();
+        // indices of every removed option in the order of removal, so choices can be undone without copying the options
+        let mut trail: Vec<usize> = Vec::new();
+        let mut choices: Vec<Choice> = Vec::new();
+        let mut backtracks = 0;
+
+        // removes the options no neighbor allows before the first choice, e.g. tiles that may not be next to anything
+        let mut consistent = self.propagate(rules, &mut options, &mut trail, (0..self.size.len()).collect());
+        if !consistent {
+            return Err(WaveFunctionCollapseError::Contradiction);
+        }
+
+        loop {
+            if consistent {
+                let Some(position) = self.lowest_entropy_position(rules, &options, rng) else {
+                    let tiles = options.chunks(tile_count).map(|options| rules.tiles[options.iter().position(|&option| option).unwrap()]);
+                    return Ok(Grid::new(self.size.width, self.size.height, tiles.collect::<Vec<_>>().into_boxed_slice()));
+                };
+
+                let tile = choose_tile(rules, &options[position * tile_count..(position + 1) * tile_count], rng);
+                choices.push(Choice {
+                    trail_len: trail.len(),
+                    position,
+                    tile,
+                });
+                for other_tile in (0..tile_count).filter(|&other_tile| other_tile != tile) {
+                    remove_option(&mut options, &mut trail, position * tile_count + other_tile);
+                }
+                consistent = self.propagate(rules, &mut options, &mut trail, vec![position]);
+            } else {
+                let Some(choice) = choices.pop() else {
+                    return Err(WaveFunctionCollapseError::Contradiction);
+                };
+                backtracks += 1;
+                if backtracks > self.max_backtracks {
+                    return Err(WaveFunctionCollapseError::Contradiction);
+                }
+
+                for index in trail.drain(choice.trail_len..) {
+                    options[index] = true;
+                }
+                // the chosen tile did not work out, so it is no option at that position anymore. the removal stays on the
+                // trail and gets undone together with the previous choice.
+                remove_option(&mut options, &mut trail, choice.position * tile_count + choice.tile);
+                let remaining = &options[choice.position * tile_count..(choice.position + 1) * tile_count];
+                consistent = remaining.contains(&true) && self.propagate(rules, &mut options, &mut trail, vec![choice.position]);
+            }
+        }
+    }
+
+    /// undecided position with the fewest and least likely options, `None` if every position is decided
+    fn lowest_entropy_position<T>(&self, rules: &AdjacencyRules<T>, options: &[bool], rng: &mut Rng) -> Option<usize> {
+        let mut lowest: Option<(f64, usize)> = None;
+        for (position, options) in options.chunks(rules.tiles.len()).enumerate() {
+            if options.iter().filter(|&&option| option).count() <= 1 {
+                continue;
+            }
+
+            let weights = options.iter().zip(&rules.weights).filter(|(&option, _)| option).map(|(_, &weight)| weight);
+            let total: f64 = weights.clone().sum();
+            let entropy = total.ln() - weights.map(|weight| weight * weight.ln()).sum::<f64>() / total;
+            // the noise breaks ties between equal positions randomly
+            let entropy = entropy + rng.next_f64() * 1e-6;
+            if lowest.is_none_or(|(lowest, _)| entropy < lowest) {
+                lowest = Some((entropy, position));
+            }
+        }
+        lowest.map(|(_, position)| position)
+    }
+
+    /// removes the options that are not allowed anymore starting at the neighbors of `pending`, `false` on a contradiction
+    fn propagate<T>(&self, rules: &AdjacencyRules<T>, options: &mut [bool], trail: &mut Vec<usize>, mut pending: Vec<usize>) -> bool {
+        let tile_count = rules.tiles.len();
+        let mut allowed = vec![false; tile_count];
+
+        while let Some(index) = pending.pop() {
+            let position = to_grid_position(index, self.size);
+            for (direction_index, direction) in Direction::CARDINAL.into_iter().enumerate() {
+                let Some(neighbor) = self.wrap_mode.wrap(position.neighbor(direction), self.size) else {
+                    continue;
+                };
+
+                allowed.fill(false);
+                for tile in (0..tile_count).filter(|&tile| options[index * tile_count + tile]) {
+                    for (allowed, &rule) in allowed.iter_mut().zip(&rules.allowed[tile][direction_index]) {
+                        *allowed |= rule;
+                    }
+                }
+
+                let neighbor = to_grid_index(neighbor, self.size);
+                let mut changed = false;
+                for tile in 0..tile_count {
+                    if options[neighbor * tile_count + tile] && !allowed[tile] {
+                        remove_option(options, trail, neighbor * tile_count + tile);
+                        changed = true;
+                    }
+                }
+
+                if !options[neighbor * tile_count..(neighbor + 1) * tile_count].contains(&true) {
+                    return false;
+                }
+                if changed {
+                    pending.push(neighbor);
+                }
+            }
+        }
+        true
+    }
+}
+
+/// removes an option and records it on the trail, does nothing if it was already removed
+fn remove_option(options: &mut [bool], trail: &mut Vec<usize>, index: usize) {
+    if options[index] {
+        options[index] = false;
+        trail.push(index);
+    }
+}
+
+fn choose_tile<T>(rules: &AdjacencyRules<T>, options: &[bool], rng: &mut Rng) -> usize {
+    let weights: Vec<_> = options.iter().zip(&rules.weights).map(|(&option, &weight)| if option { weight } else { 0. }).collect();
+    rng.weighted_index(&weights).expect("positions always keep an option with a positive weight")
+}
@@ -1,109 +1,343 @@
-use std::mem::{self, MaybeUninit};
+use std::{iter::FusedIterator, marker::PhantomData, mem};
 
-use super::{position::Position, rect_size::RectSize, to_grid_position};
+use super::{position::Position, rect_size::RectSize, to_grid_position_as, Coordinate};
 
-pub struct GridIter<'a, T>
+/// iterators that can be split into independent parts, e.g. to process them on multiple threads
+pub trait SplitIterator: ExactSizeIterator + Sized {
+    /// splits off the first `index` remaining items, the second part yields the rest.
+    ///
+    /// panics if `index` is larger than the remaining length.
+    fn split_at(self, index: usize) -> (Self, Self);
+
+    /// splits into at most `parts` iterators of nearly equal length, empty parts are omitted
+    fn split_into(self, parts: usize) -> Vec<Self> {
+        let parts = parts.max(1);
+        let mut result = Vec::with_capacity(parts);
+        let mut rest = self;
+        for remaining_parts in (1..=parts).rev() {
+            let chunk = rest.len().div_ceil(remaining_parts);
+            if chunk == 0 {
+                break;
+            }
+            let (part, tail) = rest.split_at(chunk);
+            result.push(part);
+            rest = tail;
+        }
+        result
+    }
+}
+
+pub struct GridIter<'a, T, C = i64>
 where
     T: Copy,
+    C: Coordinate,
 {
     values: &'a [T],
     size: RectSize,
-    index: i64,
+    /// grid index of the first value in `values`
+    start_index: usize,
+    coordinate: PhantomData<C>,
 }
 
-impl<'a, T> GridIter<'a, T>
+impl<'a, T, C> GridIter<'a, T, C>
 where
     T: Copy,
+    C: Coordinate,
 {
     pub fn new(values: &'a [T], size: RectSize) -> Self {
-        GridIter { values, size, index: -1 }
+        GridIter {
+            values,
+            size,
+            start_index: 0,
+            coordinate: PhantomData,
+        }
     }
 }
 
-impl<'a, T> Iterator for GridIter<'a, T>
+impl<'a, T, C> Iterator for GridIter<'a, T, C>
 where
     T: Copy,
+    C: Coordinate,
 {
-    type Item = (Position, &'a T);
+    type Item = (Position<C>, &'a T);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.index += 1;
-        if self.index as usize == self.values.len() {
-            return None;
-        }
-        Some((to_grid_position(self.index as usize, self.size), &self.values[self.index as usize]))
+        let (first, rest) = self.values.split_first()?;
+        let index = self.start_index;
+        self.values = rest;
+        self.start_index += 1;
+        Some((to_grid_position_as(index, self.size), first))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.values.len(), Some(self.values.len()))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let skipped = n.min(self.values.len());
+        self.values = &self.values[skipped..];
+        self.start_index += skipped;
+        self.next()
+    }
+}
+
+impl<T, C> DoubleEndedIterator for GridIter<'_, T, C>
+where
+    T: Copy,
+    C: Coordinate,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (last, rest) = self.values.split_last()?;
+        self.values = rest;
+        Some((to_grid_position_as(self.start_index + rest.len(), self.size), last))
+    }
+}
+
+impl<T, C> ExactSizeIterator for GridIter<'_, T, C>
+where
+    T: Copy,
+    C: Coordinate,
+{
+}
+
+impl<T, C> FusedIterator for GridIter<'_, T, C>
+where
+    T: Copy,
+    C: Coordinate,
+{
+}
+
+impl<T, C> SplitIterator for GridIter<'_, T, C>
+where
+    T: Copy,
+    C: Coordinate,
+{
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (front, back) = self.values.split_at(index);
+        (
+            GridIter {
+                values: front,
+                size: self.size,
+                start_index: self.start_index,
+                coordinate: PhantomData,
+            },
+            GridIter {
+                values: back,
+                size: self.size,
+                start_index: self.start_index + index,
+                coordinate: PhantomData,
+            },
+        )
     }
 }
 
-pub struct GridIterMut<'a, T>
+pub struct GridIterMut<'a, T, C = i64>
 where
     T: Copy,
+    C: Coordinate,
 {
     values: &'a mut [T],
     size: RectSize,
-    index: i64,
+    /// grid index of the first value in `values`
+    start_index: usize,
+    coordinate: PhantomData<C>,
 }
 
-impl<'a, T> GridIterMut<'a, T>
+impl<'a, T, C> GridIterMut<'a, T, C>
 where
     T: Copy,
+    C: Coordinate,
 {
     pub fn new(values: &'a mut [T], size: RectSize) -> Self {
-        GridIterMut { values, size, index: -1 }
+        GridIterMut {
+            values,
+            size,
+            start_index: 0,
+            coordinate: PhantomData,
+        }
     }
 }
 
-impl<'a, T> Iterator for GridIterMut<'a, T>
+impl<'a, T, C> Iterator for GridIterMut<'a, T, C>
 where
     T: Copy,
+    C: Coordinate,
 {
-    type Item = (Position, &'a mut T);
+    type Item = (Position<C>, &'a mut T);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.index += 1;
-        if self.index as usize == self.values.len() {
-            return None;
-        }
-        let val: *mut T = &mut self.values[self.index as usize];
-        Some((to_grid_position(self.index as usize, self.size), unsafe { val.as_mut().unwrap() }))
+        let (first, rest) = mem::take(&mut self.values).split_first_mut()?;
+        let index = self.start_index;
+        self.values = rest;
+        self.start_index += 1;
+        Some((to_grid_position_as(index, self.size), first))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.values.len(), Some(self.values.len()))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let skipped = n.min(self.values.len());
+        self.values = &mut mem::take(&mut self.values)[skipped..];
+        self.start_index += skipped;
+        self.next()
+    }
+}
+
+impl<T, C> DoubleEndedIterator for GridIterMut<'_, T, C>
+where
+    T: Copy,
+    C: Coordinate,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (last, rest) = mem::take(&mut self.values).split_last_mut()?;
+        let index = self.start_index + rest.len();
+        self.values = rest;
+        Some((to_grid_position_as(index, self.size), last))
     }
 }
 
-pub struct GridIntoIter<T>
+impl<T, C> ExactSizeIterator for GridIterMut<'_, T, C>
+where
+    T: Copy,
+    C: Coordinate,
+{
+}
+
+impl<T, C> FusedIterator for GridIterMut<'_, T, C>
 where
     T: Copy,
+    C: Coordinate,
+{
+}
+
+impl<T, C> SplitIterator for GridIterMut<'_, T, C>
+where
+    T: Copy,
+    C: Coordinate,
+{
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (front, back) = self.values.split_at_mut(index);
+        (
+            GridIterMut {
+                values: front,
+                size: self.size,
+                start_index: self.start_index,
+                coordinate: PhantomData,
+            },
+            GridIterMut {
+                values: back,
+                size: self.size,
+                start_index: self.start_index + index,
+                coordinate: PhantomData,
+            },
+        )
+    }
+}
+
+pub struct GridIntoIter<T, C = i64>
+where
+    T: Copy,
+    C: Coordinate,
 {
     values: Box<[T]>,
     size: RectSize,
-    index: usize,
+    /// grid index of the first value in `values`
+    start_index: usize,
+    /// index into `values` of the next value from the front
+    front: usize,
+    /// index into `values` after the next value from the back
+    back: usize,
+    coordinate: PhantomData<C>,
 }
 
-impl<T> GridIntoIter<T>
+impl<T, C> GridIntoIter<T, C>
 where
     T: Copy,
+    C: Coordinate,
 {
     pub fn new(values: Box<[T]>, size: RectSize) -> Self {
-        GridIntoIter { values, size, index: 0 }
+        let back = values.len();
+        GridIntoIter {
+            values,
+            size,
+            start_index: 0,
+            front: 0,
+            back,
+            coordinate: PhantomData,
+        }
     }
 }
 
-impl<T> Iterator for GridIntoIter<T>
+impl<T, C> Iterator for GridIntoIter<T, C>
 where
     T: Copy,
+    C: Coordinate,
 {
-    type Item = (Position, T);
+    type Item = (Position<C>, T);
+
+    fn next(&mut self) -> Option<(Position<C>, T)> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let current = self.front;
+        self.front += 1;
+        Some((to_grid_position_as(self.start_index + current, self.size), self.values[current]))
+    }
 
-    fn next(&mut self) -> Option<(Position, T)> {
-        let current_index = self.index;
-        self.index += 1;
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.back - self.front, Some(self.back - self.front))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front += n.min(self.back - self.front);
+        self.next()
+    }
+}
 
-        if current_index == self.values.len() {
+impl<T, C> DoubleEndedIterator for GridIntoIter<T, C>
+where
+    T: Copy,
+    C: Coordinate,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
             return None;
         }
 
-        Some((
-            to_grid_position(current_index, self.size),
-            mem::replace(&mut self.values[current_index], unsafe { MaybeUninit::<T>::uninit().assume_init() }),
-        ))
+        self.back -= 1;
+        Some((to_grid_position_as(self.start_index + self.back, self.size), self.values[self.back]))
+    }
+}
+
+impl<T, C> ExactSizeIterator for GridIntoIter<T, C>
+where
+    T: Copy,
+    C: Coordinate,
+{
+}
+
+impl<T, C> FusedIterator for GridIntoIter<T, C>
+where
+    T: Copy,
+    C: Coordinate,
+{
+}
+
+impl<T, C> SplitIterator for GridIntoIter<T, C>
+where
+    T: Copy,
+    C: Coordinate,
+{
+    /// copies the remaining values into two new allocations
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (front, back) = self.values[self.front..self.back].split_at(index);
+        let mut front_iter = GridIntoIter::new(front.into(), self.size);
+        front_iter.start_index = self.start_index + self.front;
+        let mut back_iter = GridIntoIter::new(back.into(), self.size);
+        back_iter.start_index = self.start_index + self.front + index;
+        (front_iter, back_iter)
     }
 }
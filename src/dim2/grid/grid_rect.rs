@@ -0,0 +1,172 @@
+use super::{patterns::PatternPositions, Coordinate, Position, RectSize};
+
+/// axis aligned rectangle of grid positions.
+///
+/// `offset` is the top left position inside the rectangle, `size` the amount of positions in each direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GridRect<C: Coordinate = i64> {
+    pub offset: Position<C>,
+    pub size: RectSize,
+}
+
+impl<C: Coordinate> GridRect<C> {
+    pub const fn new(offset: Position<C>, size: RectSize) -> Self {
+        GridRect { offset, size }
+    }
+
+    /// rectangle covering a whole grid of the given size
+    pub const fn from_size(size: RectSize) -> Self {
+        GridRect::new(Position::new(C::ZERO, C::ZERO), size)
+    }
+
+    /// smallest rectangle containing both positions
+    pub fn from_corners(a: Position<C>, b: Position<C>) -> Self {
+        let min = a.component_min(b);
+        let max = a.component_max(b);
+        GridRect::new(
+            min,
+            RectSize::new(
+                (max.x.to_i64() - min.x.to_i64()) as usize + 1,
+                (max.y.to_i64() - min.y.to_i64()) as usize + 1,
+            ),
+        )
+    }
+
+    pub fn left(&self) -> C {
+        self.offset.x
+    }
+
+    pub fn top(&self) -> C {
+        self.offset.y
+    }
+
+    /// first column to the right that is not part of the rectangle
+    pub fn right(&self) -> C {
+        self.offset.x + C::from_usize(self.size.width)
+    }
+
+    /// first row below that is not part of the rectangle
+    pub fn bottom(&self) -> C {
+        self.offset.y + C::from_usize(self.size.height)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size.is_empty()
+    }
+
+    /// center position, rounded towards the top left for even sizes
+    pub fn center(&self) -> Position<C> {
+        Position::new(
+            self.offset.x + C::from_usize(self.size.width.saturating_sub(1) / 2),
+            self.offset.y + C::from_usize(self.size.height.saturating_sub(1) / 2),
+        )
+    }
+
+    pub fn contains(&self, position: Position<C>) -> bool {
+        position.x >= self.left() && position.x < self.right() && position.y >= self.top() && position.y < self.bottom()
+    }
+
+    /// whether every position of `other` is inside of this rectangle, always true for empty rectangles
+    pub fn contains_rect(&self, other: &GridRect<C>) -> bool {
+        other.is_empty()
+            || (other.left() >= self.left() && other.right() <= self.right() && other.top() >= self.top() && other.bottom() <= self.bottom())
+    }
+
+    pub fn intersects(&self, other: &GridRect<C>) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// positions contained in both rectangles, `None` if there are none
+    pub fn intersection(&self, other: &GridRect<C>) -> Option<GridRect<C>> {
+        let left = self.left().max(other.left());
+        let top = self.top().max(other.top());
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+
+        if left >= right || top >= bottom {
+            return None;
+        }
+
+        Some(GridRect::new(
+            Position::new(left, top),
+            RectSize::new((right - left).to_i64() as usize, (bottom - top).to_i64() as usize),
+        ))
+    }
+
+    /// smallest rectangle containing both rectangles, empty rectangles are ignored
+    pub fn union(&self, other: &GridRect<C>) -> GridRect<C> {
+        if other.is_empty() {
+            return *self;
+        }
+        if self.is_empty() {
+            return *other;
+        }
+
+        let left = self.left().min(other.left());
+        let top = self.top().min(other.top());
+        let right = self.right().max(other.right());
+        let bottom = self.bottom().max(other.bottom());
+
+        GridRect::new(
+            Position::new(left, top),
+            RectSize::new((right - left).to_i64() as usize, (bottom - top).to_i64() as usize),
+        )
+    }
+
+    /// grows the rectangle by `amount` positions into every direction
+    pub fn expand(&self, amount: usize) -> GridRect<C> {
+        let amount_c = C::from_usize(amount);
+        GridRect::new(
+            Position::new(self.offset.x - amount_c, self.offset.y - amount_c),
+            RectSize::new(self.size.width + 2 * amount, self.size.height + 2 * amount),
+        )
+    }
+
+    /// shrinks the rectangle by `amount` positions from every direction, down to an empty rectangle
+    pub fn shrink(&self, amount: usize) -> GridRect<C> {
+        let width = self.size.width.saturating_sub(2 * amount);
+        let height = self.size.height.saturating_sub(2 * amount);
+        let shift_x = C::from_usize((self.size.width - width) / 2);
+        let shift_y = C::from_usize((self.size.height - height) / 2);
+        GridRect::new(
+            Position::new(self.offset.x + shift_x, self.offset.y + shift_y),
+            RectSize::new(width, height),
+        )
+    }
+
+    /// splits into a left part with `width` columns and the remaining right part
+    pub fn split_horizontally(&self, width: usize) -> (GridRect<C>, GridRect<C>) {
+        let width = width.min(self.size.width);
+        (
+            GridRect::new(self.offset, RectSize::new(width, self.size.height)),
+            GridRect::new(
+                Position::new(self.offset.x + C::from_usize(width), self.offset.y),
+                RectSize::new(self.size.width - width, self.size.height),
+            ),
+        )
+    }
+
+    /// splits into a top part with `height` rows and the remaining bottom part
+    pub fn split_vertically(&self, height: usize) -> (GridRect<C>, GridRect<C>) {
+        let height = height.min(self.size.height);
+        (
+            GridRect::new(self.offset, RectSize::new(self.size.width, height)),
+            GridRect::new(
+                Position::new(self.offset.x, self.offset.y + C::from_usize(height)),
+                RectSize::new(self.size.width, self.size.height - height),
+            ),
+        )
+    }
+
+    /// every position inside the rectangle in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = Position<C>> + '_ {
+        self.size.iter_as::<C>().map(|pos| pos + self.offset)
+    }
+}
+
+/// interprets the rectangle relative to the pattern center, e.g. an offset of (-1, -1) with a size of 3x3 covers the surrounding area
+impl<C: Coordinate> PatternPositions<C> for GridRect<C> {
+    fn get_pattern_positions(&self, center: Position<C>) -> Box<[Position<C>]> {
+        self.iter().map(|pos| pos + center).collect::<Vec<_>>().into_boxed_slice()
+    }
+}
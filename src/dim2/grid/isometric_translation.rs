@@ -0,0 +1,142 @@
+use crate::{
+    dim2::grid::{Position, RectSize},
+    ScreenView,
+};
+
+/// arrangement of the isometric tiles on screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsometricLayout {
+    /// the whole grid forms one big diamond, tile (0, 0) is the top corner
+    Diamond,
+    /// tiles are laid out in rows where every odd row is shifted right by half a tile
+    Staggered,
+}
+
+/// converts points inside a grid into the screen coordinates of an isometric projection.
+///
+/// tiles are diamonds of `tile_width` x `tile_height` that are stretched so the projected grid fills the `ScreenView`.
+#[derive(Clone, Copy)]
+pub struct IsometricTranslation {
+    screen_view: ScreenView,
+    grid_size: RectSize,
+    layout: IsometricLayout,
+    tile_width: f32,
+    tile_height: f32,
+}
+
+impl IsometricTranslation {
+    pub fn new(screen_view: ScreenView, grid_size: RectSize, layout: IsometricLayout) -> IsometricTranslation {
+        let (tile_width, tile_height) = match layout {
+            IsometricLayout::Diamond => {
+                let diagonal_tiles = (grid_size.width + grid_size.height) as f32;
+                (2. * screen_view.width / diagonal_tiles, 2. * screen_view.height / diagonal_tiles)
+            }
+            IsometricLayout::Staggered => (
+                screen_view.width / (grid_size.width as f32 + 0.5),
+                2. * screen_view.height / (grid_size.height as f32 + 1.),
+            ),
+        };
+
+        IsometricTranslation {
+            screen_view,
+            grid_size,
+            layout,
+            tile_width,
+            tile_height,
+        }
+    }
+
+    pub fn layout(&self) -> IsometricLayout {
+        self.layout
+    }
+
+    pub fn tile_width(&self) -> f32 {
+        self.tile_width
+    }
+
+    pub fn tile_height(&self) -> f32 {
+        self.tile_height
+    }
+
+    /// picks the tile whose diamond contains the screen point.
+    ///
+    /// returns `None` for points outside of the `ScreenView` or outside of every tile.
+    pub fn get_grid_position(&self, screen_x: f32, screen_y: f32) -> Option<Position> {
+        if !self.in_screen_bounds(screen_x, screen_y) {
+            return None;
+        }
+
+        let local_x = screen_x - self.screen_view.offset_x;
+        let local_y = screen_y - self.screen_view.offset_y;
+
+        let position = match self.layout {
+            IsometricLayout::Diamond => self.pick_diamond(local_x, local_y),
+            IsometricLayout::Staggered => self.pick_staggered(local_x, local_y),
+        };
+
+        if self.in_grid_bounds(position) {
+            Some(position)
+        } else {
+            None
+        }
+    }
+
+    pub fn block_center_to_screen_position(&self, x: i64, y: i64) -> (f32, f32) {
+        let half_width = self.tile_width / 2.;
+        let half_height = self.tile_height / 2.;
+
+        let (center_x, center_y) = match self.layout {
+            IsometricLayout::Diamond => (
+                self.grid_size.height as f32 * half_width + (x - y) as f32 * half_width,
+                (x + y) as f32 * half_height + half_height,
+            ),
+            IsometricLayout::Staggered => {
+                let row_shift = if y.rem_euclid(2) == 1 { half_width } else { 0. };
+                (x as f32 * self.tile_width + half_width + row_shift, y as f32 * half_height + half_height)
+            }
+        };
+
+        (self.screen_view.offset_x + center_x, self.screen_view.offset_y + center_y)
+    }
+
+    fn pick_diamond(&self, local_x: f32, local_y: f32) -> Position {
+        // offsets in half tiles relative to the top corner of tile (0, 0)
+        let diagonal_x = (local_x - self.grid_size.height as f32 * self.tile_width / 2.) / (self.tile_width / 2.);
+        let diagonal_y = local_y / (self.tile_height / 2.);
+
+        Position::new(
+            ((diagonal_y + diagonal_x) / 2.).floor() as i64,
+            ((diagonal_y - diagonal_x) / 2.).floor() as i64,
+        )
+    }
+
+    fn pick_staggered(&self, local_x: f32, local_y: f32) -> Position {
+        // every rectangle of a full tile size contains the diamond of an even row tile,
+        // its four corners belong to the neighboring odd row tiles
+        let column = (local_x / self.tile_width).floor() as i64;
+        let double_row = (local_y / self.tile_height).floor() as i64;
+
+        let relative_x = (local_x - (column as f32 + 0.5) * self.tile_width) / (self.tile_width / 2.);
+        let relative_y = (local_y - (double_row as f32 + 0.5) * self.tile_height) / (self.tile_height / 2.);
+
+        if relative_x.abs() + relative_y.abs() <= 1. {
+            return Position::new(column, 2 * double_row);
+        }
+
+        let x = if relative_x < 0. { column - 1 } else { column };
+        let y = if relative_y < 0. { 2 * double_row - 1 } else { 2 * double_row + 1 };
+        Position::new(x, y)
+    }
+
+    fn in_screen_bounds(&self, screen_x: f32, screen_y: f32) -> bool {
+        (self.screen_view.offset_x..self.screen_view.offset_x + self.screen_view.width).contains(&screen_x)
+            && (self.screen_view.offset_y..self.screen_view.offset_y + self.screen_view.height).contains(&screen_y)
+    }
+
+    fn in_grid_bounds(&self, position: Position) -> bool {
+        position.x >= 0
+            && position.y >= 0
+            && (position.x as usize) < self.grid_size.width
+            && (position.y as usize) < self.grid_size.height
+    }
+}
@@ -0,0 +1,146 @@
+use super::{Coordinate, Position, RectSize};
+
+/// order in which the positions of a `RectSize` or `Grid` are visited
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IterationOrder {
+    /// row by row from the top left, the default order of `RectSize::iter`
+    RowMajor,
+    /// column by column from the top left
+    ColumnMajor,
+    /// row by row from the bottom right
+    Reverse,
+    /// square spiral starting at the center, first moving right and then turning clockwise
+    SpiralFromCenter,
+    /// morton order, visits quadrants recursively in a Z shape
+    ZOrder,
+    /// hilbert curve, every position is adjacent to the previous one on square power of two sizes
+    Hilbert,
+    /// row by row, every odd row from right to left
+    Serpentine,
+}
+
+impl IterationOrder {
+    /// every position of `size` in this order
+    pub fn positions<C: Coordinate>(&self, size: RectSize) -> Vec<Position<C>> {
+        let (width, height) = (size.width as i64, size.height as i64);
+        let positions: Vec<(i64, i64)> = match self {
+            IterationOrder::RowMajor => (0..height).flat_map(|y| (0..width).map(move |x| (x, y))).collect(),
+            IterationOrder::ColumnMajor => (0..width).flat_map(|x| (0..height).map(move |y| (x, y))).collect(),
+            IterationOrder::Reverse => (0..height).rev().flat_map(|y| (0..width).rev().map(move |x| (x, y))).collect(),
+            IterationOrder::Serpentine => (0..height)
+                .flat_map(|y| (0..width).map(move |x| if y % 2 == 0 { (x, y) } else { (width - 1 - x, y) }))
+                .collect(),
+            IterationOrder::SpiralFromCenter => spiral_positions(width, height),
+            IterationOrder::ZOrder => curve_positions(width, height, morton_to_position),
+            IterationOrder::Hilbert => curve_positions(width, height, hilbert_to_position),
+        };
+
+        positions.into_iter().map(|(x, y)| Position::new(C::from_i64(x), C::from_i64(y))).collect()
+    }
+}
+
+fn spiral_positions(width: i64, height: i64) -> Vec<(i64, i64)> {
+    let total = (width * height) as usize;
+    let mut result = Vec::with_capacity(total);
+    if total == 0 {
+        return result;
+    }
+
+    let directions = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+    let (mut x, mut y) = ((width - 1) / 2, (height - 1) / 2);
+    result.push((x, y));
+
+    // leg lengths grow by one after every second turn: 1, 1, 2, 2, 3, 3, ...
+    let mut leg_length = 1;
+    let mut direction = 0;
+    while result.len() < total {
+        for _ in 0..2 {
+            let (dx, dy) = directions[direction % 4];
+            for _ in 0..leg_length {
+                x += dx;
+                y += dy;
+                if (0..width).contains(&x) && (0..height).contains(&y) {
+                    result.push((x, y));
+                }
+            }
+            direction += 1;
+        }
+        leg_length += 1;
+    }
+    result
+}
+
+/// walks a space filling curve over the smallest enclosing power of two square and skips positions outside of the size
+fn curve_positions(width: i64, height: i64, index_to_position: fn(i64, i64) -> (i64, i64)) -> Vec<(i64, i64)> {
+    let side = (width.max(height).max(1) as u64).next_power_of_two() as i64;
+    let mut result = Vec::with_capacity((width * height) as usize);
+    curve_block(width, height, side, 0, side, index_to_position, &mut result);
+    result
+}
+
+/// adds the positions of the `block_side * block_side` indices starting at `start`.
+///
+/// such a block always covers an aligned square, so blocks outside of the size are skipped without visiting their indices.
+fn curve_block(
+    width: i64,
+    height: i64,
+    side: i64,
+    start: i64,
+    block_side: i64,
+    index_to_position: fn(i64, i64) -> (i64, i64),
+    result: &mut Vec<(i64, i64)>,
+) {
+    let (x, y) = index_to_position(side, start);
+    let (left, top) = (x - x % block_side, y - y % block_side);
+    if left >= width || top >= height {
+        return;
+    }
+
+    let block_len = block_side * block_side;
+    if block_side == 1 || (left + block_side <= width && top + block_side <= height) {
+        result.extend((start..start + block_len).map(|index| index_to_position(side, index)));
+    } else {
+        let quarter = block_len / 4;
+        for i in 0..4 {
+            curve_block(width, height, side, start + i * quarter, block_side / 2, index_to_position, result);
+        }
+    }
+}
+
+fn morton_to_position(_side: i64, index: i64) -> (i64, i64) {
+    (compact_bits(index), compact_bits(index >> 1))
+}
+
+/// keeps every second bit of `value`, starting with the lowest
+fn compact_bits(value: i64) -> i64 {
+    let mut result = 0;
+    let mut bit = 0;
+    while value >> (2 * bit) != 0 {
+        result |= ((value >> (2 * bit)) & 1) << bit;
+        bit += 1;
+    }
+    result
+}
+
+fn hilbert_to_position(side: i64, index: i64) -> (i64, i64) {
+    let (mut x, mut y) = (0, 0);
+    let mut remaining = index;
+    let mut size = 1;
+    while size < side {
+        let rx = 1 & (remaining / 2);
+        let ry = 1 & (remaining ^ rx);
+        // rotate the quadrant so the sub curves connect
+        if ry == 0 {
+            if rx == 1 {
+                x = size - 1 - x;
+                y = size - 1 - y;
+            }
+            std::mem::swap(&mut x, &mut y);
+        }
+        x += size * rx;
+        y += size * ry;
+        remaining /= 4;
+        size *= 2;
+    }
+    (x, y)
+}
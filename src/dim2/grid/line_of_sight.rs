@@ -0,0 +1,64 @@
+use super::{Coordinate, Grid, Position, WrapMode};
+
+/// positions on the straight line from `from` to `to` including both ends, using Bresenham's algorithm
+pub fn line_positions<C: Coordinate>(from: Position<C>, to: Position<C>) -> Vec<Position<C>> {
+    let (dx, dy) = (to.x.to_i64() - from.x.to_i64(), to.y.to_i64() - from.y.to_i64());
+    line_offsets(dx, dy)
+        .into_iter()
+        .map(|(x, y)| Position::new(C::from_i64(from.x.to_i64() + x), C::from_i64(from.y.to_i64() + y)))
+        .collect()
+}
+
+fn line_offsets(dx: i64, dy: i64) -> Vec<(i64, i64)> {
+    let (step_x, step_y) = (dx.signum(), dy.signum());
+    let (dx, dy) = (dx.abs(), -dy.abs());
+    let mut error = dx + dy;
+    let (mut x, mut y) = (0, 0);
+
+    let mut offsets = Vec::with_capacity(dx.max(-dy) as usize + 1);
+    loop {
+        offsets.push((x, y));
+        if x == step_x * dx && y == step_y * -dy {
+            return offsets;
+        }
+
+        let doubled_error = 2 * error;
+        if doubled_error >= dy {
+            error += dy;
+            x += step_x;
+        }
+        if doubled_error <= dx {
+            error += dx;
+            y += step_y;
+        }
+    }
+}
+
+/// checks if every tile between `from` and `to` lets sight through.
+///
+/// the tiles at `from` and `to` themselves are not checked, so an opaque tile can still be seen.
+/// tiles outside of the grid block the line.
+pub fn has_line_of_sight<T: Copy, C: Coordinate>(
+    grid: &Grid<T>,
+    from: Position<C>,
+    to: Position<C>,
+    is_transparent_tile: &dyn Fn(T) -> bool,
+) -> bool {
+    has_wrapped_line_of_sight(grid, from, to, WrapMode::None, is_transparent_tile)
+}
+
+/// like `has_line_of_sight`, but the line takes the shortest way across the border on the axes of `wrap_mode`
+pub fn has_wrapped_line_of_sight<T: Copy, C: Coordinate>(
+    grid: &Grid<T>,
+    from: Position<C>,
+    to: Position<C>,
+    wrap_mode: WrapMode,
+    is_transparent_tile: &dyn Fn(T) -> bool,
+) -> bool {
+    let (dx, dy) = wrap_mode.delta(from, to, grid.size());
+    let offsets = line_offsets(dx, dy);
+    offsets.iter().skip(1).take(offsets.len().saturating_sub(2)).all(|&(x, y)| {
+        let position = Position::new(from.x.to_i64() + x, from.y.to_i64() + y);
+        grid.get_wrapped(position, wrap_mode).is_some_and(is_transparent_tile)
+    })
+}
@@ -3,24 +3,64 @@ use std::slice::{Iter, IterMut};
 
 use grid_iter::{GridIntoIter, GridIter, GridIterMut};
 
+pub mod automaton;
+pub use automaton::{CellularAutomaton, LifeRule};
+pub mod binary;
+pub use binary::{GridDecodeError, GridEncoding};
+pub mod camera;
+pub use camera::*;
+pub mod convolution;
+pub use convolution::EdgeHandling;
+pub mod coordinate;
+pub use coordinate::Coordinate;
+pub mod direction;
+pub use direction::Direction;
+pub mod generators;
 pub mod grid_iter;
+pub mod grid_rect;
+pub use grid_rect::GridRect;
+pub mod isometric_translation;
+pub use isometric_translation::*;
+pub mod iteration_order;
+pub use iteration_order::IterationOrder;
+pub mod line_of_sight;
+pub mod parallel;
 pub mod pathing;
 pub mod patterns;
 pub mod position;
 pub use position::Position;
 pub mod rect_size;
 pub use rect_size::RectSize;
+pub mod screen_pathing;
 pub mod screen_translation;
 pub use screen_translation::*;
+pub mod text_map;
+pub use text_map::TextMapError;
+pub mod tile_point;
+pub use tile_point::*;
+pub mod viewports;
+pub use viewports::ViewportManager;
+pub mod wrapping;
+pub use wrapping::WrapMode;
 
 pub fn to_grid_index(position: Position, size: RectSize) -> usize {
-    position.y as usize * size.width + position.x as usize
+    to_grid_index_as(position, size)
+}
+
+/// like `to_grid_index`, but for positions of any coordinate type
+pub fn to_grid_index_as<C: Coordinate>(position: Position<C>, size: RectSize) -> usize {
+    position.y.to_i64() as usize * size.width + position.x.to_i64() as usize
 }
 
 pub fn to_grid_position(index: usize, size: RectSize) -> Position {
+    to_grid_position_as(index, size)
+}
+
+/// like `to_grid_position`, but with a position of the given coordinate type
+pub fn to_grid_position_as<C: Coordinate>(index: usize, size: RectSize) -> Position<C> {
     Position {
-        x: (index % size.width) as i64,
-        y: (index / size.height) as i64,
+        x: C::from_usize(index % size.width),
+        y: C::from_usize(index / size.width),
     }
 }
 
@@ -66,17 +106,29 @@ where
     }
 
     pub fn get(&self, position: Position) -> Option<T> {
+        self.get_as(position)
+    }
+
+    /// like `get`, but for positions of any coordinate type
+    pub fn get_as<C: Coordinate>(&self, position: Position<C>) -> Option<T> {
         if self.in_bounds(position) {
-            Some(self.values[to_grid_index(position, self.size)])
+            Some(self.values[to_grid_index_as(position, self.size)])
         } else {
             None
         }
     }
 
     // TODO: add error type
+    #[allow(clippy::result_unit_err)]
     pub fn set(&mut self, position: Position, value: T) -> Result<(), ()> {
+        self.set_as(position, value)
+    }
+
+    /// like `set`, but for positions of any coordinate type
+    #[allow(clippy::result_unit_err)]
+    pub fn set_as<C: Coordinate>(&mut self, position: Position<C>, value: T) -> Result<(), ()> {
         if self.in_bounds(position) {
-            self.values[to_grid_index(position, self.size)] = value;
+            self.values[to_grid_index_as(position, self.size)] = value;
             Ok(())
         } else {
             Err(())
@@ -84,36 +136,93 @@ where
     }
 
     pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.get_mut_as(position)
+    }
+
+    /// like `get_mut`, but for positions of any coordinate type
+    pub fn get_mut_as<C: Coordinate>(&mut self, position: Position<C>) -> Option<&mut T> {
         if self.in_bounds(position) {
-            Some(&mut self.values[to_grid_index(position, self.size)])
+            Some(&mut self.values[to_grid_index_as(position, self.size)])
         } else {
             None
         }
     }
 
     pub fn get_sub_grid(&self, offset: Position, size: RectSize) -> Option<Grid<T>> {
+        self.get_sub_grid_as(offset, size)
+    }
+
+    /// like `get_sub_grid`, but for an offset of any coordinate type
+    pub fn get_sub_grid_as<C: Coordinate>(&self, offset: Position<C>, size: RectSize) -> Option<Grid<T>> {
         let mut values = Vec::new();
-        for pos in size.iter() {
+        for pos in size.iter_as::<C>() {
             let grid_pos = offset + pos;
             if !self.in_bounds(offset + pos) {
                 return None;
             }
 
-            values.push(self.values[to_grid_index(grid_pos, self.size)]);
+            values.push(self.values[to_grid_index_as(grid_pos, self.size)]);
         }
 
         Some(Grid::new(size.width, size.height, values.into_boxed_slice()))
     }
 
+    /// rectangle covering every position of the grid
+    pub const fn rect(&self) -> GridRect {
+        GridRect::from_size(self.size)
+    }
+
+    /// copies the values inside of `rect`, `None` if the rectangle is not completely inside of the grid
+    pub fn get_region(&self, rect: GridRect) -> Option<Grid<T>> {
+        self.get_region_as(rect)
+    }
+
+    /// like `get_region`, but for rectangles of any coordinate type
+    pub fn get_region_as<C: Coordinate>(&self, rect: GridRect<C>) -> Option<Grid<T>> {
+        self.get_sub_grid_as(rect.offset, rect.size)
+    }
+
+    /// creates a new grid of the same size with `f` applied to every value
+    pub fn map<U, F>(&self, mut f: F) -> Grid<U>
+    where
+        U: Copy,
+        F: FnMut(T) -> U,
+    {
+        self.map_with_position(|_, value| f(value))
+    }
+
+    pub fn map_with_position<U, F>(&self, mut f: F) -> Grid<U>
+    where
+        U: Copy,
+        F: FnMut(Position, T) -> U,
+    {
+        let values: Vec<_> = self.iter_with_position().map(|(pos, &value)| f(pos, value)).collect();
+        Grid::new(self.width(), self.height(), values.into_boxed_slice())
+    }
+
+    /// combines the values at the same position of both grids.
+    ///
+    /// panics if the grids differ in size.
+    pub fn zip_with<U, V, F>(&self, other: &Grid<U>, mut f: F) -> Grid<V>
+    where
+        U: Copy,
+        V: Copy,
+        F: FnMut(T, U) -> V,
+    {
+        assert!(self.size == other.size, "zipped grids must have the same size");
+        let values: Vec<_> = self.iter().zip(other.iter()).map(|(&value, &other_value)| f(value, other_value)).collect();
+        Grid::new(self.width(), self.height(), values.into_boxed_slice())
+    }
+
     pub fn into_array(self) -> Box<[T]> {
         self.values
     }
 
-    pub fn iter_with_position(&self) -> GridIter<T> {
+    pub fn iter_with_position(&self) -> GridIter<'_, T> {
         GridIter::new(&self.values, self.size)
     }
 
-    pub fn iter_mut_with_position(&mut self) -> GridIterMut<T> {
+    pub fn iter_mut_with_position(&mut self) -> GridIterMut<'_, T> {
         GridIterMut::new(&mut self.values, self.size)
     }
 
@@ -121,16 +230,50 @@ where
         GridIntoIter::new(self.values, self.size)
     }
 
-    pub fn iter(&self) -> Iter<T> {
+    /// like `iter_with_position`, but with positions of the given coordinate type
+    pub fn iter_with_position_as<C: Coordinate>(&self) -> GridIter<'_, T, C> {
+        GridIter::new(&self.values, self.size)
+    }
+
+    /// like `iter_mut_with_position`, but with positions of the given coordinate type
+    pub fn iter_mut_with_position_as<C: Coordinate>(&mut self) -> GridIterMut<'_, T, C> {
+        GridIterMut::new(&mut self.values, self.size)
+    }
+
+    /// like `into_iter_with_position`, but with positions of the given coordinate type
+    pub fn into_iter_with_position_as<C: Coordinate>(self) -> GridIntoIter<T, C> {
+        GridIntoIter::new(self.values, self.size)
+    }
+
+    /// iterates over all values with their position in the given order
+    pub fn iter_with_position_ordered(&self, order: IterationOrder) -> impl Iterator<Item = (Position, &T)> {
+        order
+            .positions(self.size)
+            .into_iter()
+            .map(|pos| (pos, &self.values[to_grid_index(pos, self.size)]))
+    }
+
+    /// iterates mutably over all values with their position in the given order
+    pub fn iter_mut_with_position_ordered(&mut self, order: IterationOrder) -> impl Iterator<Item = (Position, &mut T)> {
+        let size = self.size;
+        let mut values: Vec<Option<&mut T>> = self.values.iter_mut().map(Some).collect();
+        order.positions(size).into_iter().map(move |pos| {
+            let value = values[to_grid_index(pos, size)].take().expect("iteration orders visit every position once");
+            (pos, value)
+        })
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
         self.values.iter()
     }
 
-    pub fn iter_mut(&mut self) -> IterMut<T> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
         self.values.iter_mut()
     }
 
-    const fn in_bounds(&self, position: Position) -> bool {
-        position.x >= 0 && position.y >= 0 && (position.x as usize) < self.size.width && (position.y as usize) < self.size.height
+    fn in_bounds<C: Coordinate>(&self, position: Position<C>) -> bool {
+        let (x, y) = (position.x.to_i64(), position.y.to_i64());
+        x >= 0 && y >= 0 && (x as usize) < self.size.width && (y as usize) < self.size.height
     }
 }
 
@@ -144,6 +287,7 @@ where
     }
 }
 
+/// indexing only takes positions with the default `i64` coordinates, see `get_as` and `get_mut_as` for other types
 impl<T> Index<Position> for &Grid<T>
 where
     T: Copy,
@@ -0,0 +1,105 @@
+use std::{num::NonZeroUsize, thread};
+
+use super::{grid_iter::SplitIterator, to_grid_index, Grid, Position};
+
+/// grids smaller than this per available thread are processed with fewer threads
+const MIN_VALUES_PER_THREAD: usize = 4096;
+
+fn thread_count(len: usize) -> usize {
+    let available = thread::available_parallelism().map(NonZeroUsize::get).unwrap_or(1);
+    available.min(len / MIN_VALUES_PER_THREAD).max(1)
+}
+
+/// parallel versions of the grid operations, running on scoped threads.
+///
+/// the returned grids and the values written by `par_for_each_mut_with_position` are identical to processing the values
+/// sequentially in `iter_with_position` order. the callbacks run in no particular order though, so their side effects
+/// can happen in any order.
+impl<T> Grid<T>
+where
+    T: Copy + Send + Sync,
+{
+    pub fn par_for_each_with_position<F>(&self, f: F)
+    where
+        F: Fn(Position, &T) + Sync,
+    {
+        let parts = self.iter_with_position().split_into(thread_count(self.len()));
+        thread::scope(|scope| {
+            for part in parts {
+                scope.spawn(|| part.for_each(|(pos, value)| f(pos, value)));
+            }
+        });
+    }
+
+    pub fn par_for_each_mut_with_position<F>(&mut self, f: F)
+    where
+        F: Fn(Position, &mut T) + Sync,
+    {
+        let thread_count = thread_count(self.len());
+        let parts = self.iter_mut_with_position().split_into(thread_count);
+        thread::scope(|scope| {
+            for part in parts {
+                scope.spawn(|| part.for_each(|(pos, value)| f(pos, value)));
+            }
+        });
+    }
+
+    pub fn par_map<U, F>(&self, f: F) -> Grid<U>
+    where
+        U: Copy + Send,
+        F: Fn(T) -> U + Sync,
+    {
+        self.par_map_with_position(|_, value| f(value))
+    }
+
+    pub fn par_map_with_position<U, F>(&self, f: F) -> Grid<U>
+    where
+        U: Copy + Send,
+        F: Fn(Position, T) -> U + Sync,
+    {
+        let parts = self.iter_with_position().split_into(thread_count(self.len()));
+        let values = thread::scope(|scope| {
+            let handles: Vec<_> = parts
+                .into_iter()
+                .map(|part| scope.spawn(|| part.map(|(pos, &value)| f(pos, value)).collect::<Vec<_>>()))
+                .collect();
+            join_in_order(handles)
+        });
+        Grid::new(self.width(), self.height(), values.into_boxed_slice())
+    }
+
+    /// combines the values at the same position of both grids.
+    ///
+    /// panics if the grids differ in size.
+    pub fn par_zip_map<U, V, F>(&self, other: &Grid<U>, f: F) -> Grid<V>
+    where
+        U: Copy + Send + Sync,
+        V: Copy + Send,
+        F: Fn(Position, T, U) -> V + Sync,
+    {
+        assert!(self.size() == other.size(), "zipped grids must have the same size");
+
+        let parts = self.iter_with_position().split_into(thread_count(self.len()));
+        let values = thread::scope(|scope| {
+            let handles: Vec<_> = parts
+                .into_iter()
+                .map(|part| {
+                    scope.spawn(|| {
+                        part.map(|(pos, &value)| f(pos, value, other.values[to_grid_index(pos, other.size)]))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            join_in_order(handles)
+        });
+        Grid::new(self.width(), self.height(), values.into_boxed_slice())
+    }
+}
+
+fn join_in_order<U>(handles: Vec<thread::ScopedJoinHandle<'_, Vec<U>>>) -> Vec<U> {
+    let mut values = vec![];
+    for handle in handles {
+        values.append(&mut handle.join().expect("grid worker thread panicked"));
+    }
+    values
+}
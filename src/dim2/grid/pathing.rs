@@ -2,39 +2,86 @@ use pathfinding::prelude::astar;
 
 use super::{
     patterns::{adjacent_pattern::adjacent_pattern, PatternPositions},
-    position::Position,
-    Grid,
+    position::{DistanceMetric, Position},
+    Coordinate, Grid, WrapMode,
 };
 
-pub fn path_exists<T: Copy>(grid: &Grid<T>, start: Position, end: Position, is_pathable_tile: &dyn Fn(T) -> bool) -> bool {
+pub fn path_exists<T: Copy, C: Coordinate>(grid: &Grid<T>, start: Position<C>, end: Position<C>, is_pathable_tile: &dyn Fn(T) -> bool) -> bool {
     get_shortest_path(grid, start, end, is_pathable_tile).is_some()
 }
 
-pub fn get_shortest_path<T: Copy>(
+pub fn get_shortest_path<T: Copy, C: Coordinate>(
     grid: &Grid<T>,
-    start: Position,
-    end: Position,
+    start: Position<C>,
+    end: Position<C>,
     is_pathable_tile: &dyn Fn(T) -> bool,
-) -> Option<(Vec<Position>, i64)> {
-    let cost = |node: &Position| {
-        let distance = end - *node;
-        distance.x.abs() + distance.y.abs()
-    };
+) -> Option<(Vec<Position<C>>, i64)> {
+    get_shortest_path_with_heuristic(grid, start, end, DistanceMetric::Manhattan, is_pathable_tile)
+}
+
+/// finds the shortest path using `heuristic` to estimate the remaining distance to `end`.
+///
+/// `Manhattan` is exact for the horizontal and vertical steps taken. metrics that can overestimate
+/// the remaining steps, like `EuclideanSquared`, explore fewer tiles but might not find the shortest path.
+pub fn get_shortest_path_with_heuristic<T: Copy, C: Coordinate>(
+    grid: &Grid<T>,
+    start: Position<C>,
+    end: Position<C>,
+    heuristic: DistanceMetric,
+    is_pathable_tile: &dyn Fn(T) -> bool,
+) -> Option<(Vec<Position<C>>, i64)> {
+    astar(
+        &start,
+        |node| get_neighbors(grid, *node, WrapMode::None, is_pathable_tile),
+        |node| heuristic.distance(*node, end) as i64,
+        |node| *node == end,
+    )
+}
+
+pub fn wrapped_path_exists<T: Copy, C: Coordinate>(
+    grid: &Grid<T>,
+    start: Position<C>,
+    end: Position<C>,
+    wrap_mode: WrapMode,
+    is_pathable_tile: &dyn Fn(T) -> bool,
+) -> bool {
+    get_shortest_wrapped_path(grid, start, end, wrap_mode, is_pathable_tile).is_some()
+}
+
+/// finds the shortest path on a grid that continues on the opposite side on the axes of `wrap_mode`.
+///
+/// the positions of the path are always inside the grid, steps across the border connect positions on opposite sides.
+pub fn get_shortest_wrapped_path<T: Copy, C: Coordinate>(
+    grid: &Grid<T>,
+    start: Position<C>,
+    end: Position<C>,
+    wrap_mode: WrapMode,
+    is_pathable_tile: &dyn Fn(T) -> bool,
+) -> Option<(Vec<Position<C>>, i64)> {
+    let size = grid.size();
+    let start = wrap_mode.wrap(start, size)?;
+    let end = wrap_mode.wrap(end, size)?;
     astar(
         &start,
-        |node| get_neighbors(grid, *node, is_pathable_tile),
-        cost,
+        |node| get_neighbors(grid, *node, wrap_mode, is_pathable_tile),
+        |node| DistanceMetric::Manhattan.wrapped_distance(*node, end, size, wrap_mode) as i64,
         |node| *node == end,
     )
 }
 
-fn get_neighbors<T: Copy>(grid: &Grid<T>, node: Position, is_pathable_tile: &dyn Fn(T) -> bool) -> Vec<(Position, i64)> {
+fn get_neighbors<T: Copy, C: Coordinate>(
+    grid: &Grid<T>,
+    node: Position<C>,
+    wrap_mode: WrapMode,
+    is_pathable_tile: &dyn Fn(T) -> bool,
+) -> Vec<(Position<C>, i64)> {
     adjacent_pattern()
         .get_pattern_positions(node)
         .iter()
         .filter_map(|&pos| {
-            let entity = grid.get(pos)?;
-            if is_pathable_tile(entity) {
+            let pos = wrap_mode.wrap(pos, grid.size())?;
+            let entity = grid.get_as(pos)?;
+            if pos != node && is_pathable_tile(entity) {
                 Some((pos, 1))
             } else {
                 None
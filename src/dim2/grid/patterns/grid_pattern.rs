@@ -1,18 +1,18 @@
 use super::PatternPositions;
-use crate::dim2::grid::{Grid, Position};
+use crate::dim2::grid::{Coordinate, Grid, Position};
 
 pub struct GridPattern {
     pub mapping: Grid<bool>,
     pub center: Position,
 }
 
-impl PatternPositions for GridPattern {
-    fn get_pattern_positions(&self, center: Position) -> Box<[Position]> {
+impl<C: Coordinate> PatternPositions<C> for GridPattern {
+    fn get_pattern_positions(&self, center: Position<C>) -> Box<[Position<C>]> {
         let mut result = vec![];
 
-        let offset = center - self.center;
+        let offset = center - Position::new(C::from_i64(self.center.x), C::from_i64(self.center.y));
 
-        for (pos, &matches) in self.mapping.iter_with_position() {
+        for (pos, &matches) in self.mapping.iter_with_position_as::<C>() {
             if matches {
                 result.push(pos + offset);
             }
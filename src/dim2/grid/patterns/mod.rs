@@ -10,23 +10,27 @@ pub use grid_pattern::*;
 pub mod surrounding_pattern;
 pub use surrounding_pattern::*;
 
+pub mod radius_pattern;
+pub use radius_pattern::*;
+
 pub mod rectangle_pattern;
 pub use rectangle_pattern::*;
 
-use super::{Grid, Position};
+use super::{Coordinate, Grid, Position};
 
-pub trait PatternPositions {
-    fn get_pattern_positions(&self, center: Position) -> Box<[Position]>;
+pub trait PatternPositions<C: Coordinate = i64> {
+    fn get_pattern_positions(&self, center: Position<C>) -> Box<[Position<C>]>;
 }
 
-pub fn get_grid_values_from_pattern<T>(grid: &Grid<T>, center: Position, pattern: &dyn PatternPositions) -> Box<[T]>
+pub fn get_grid_values_from_pattern<T, C>(grid: &Grid<T>, center: Position<C>, pattern: &dyn PatternPositions<C>) -> Box<[T]>
 where
     T: Copy,
+    C: Coordinate,
 {
     pattern
         .get_pattern_positions(center)
         .iter()
-        .filter_map(|&pos| grid.get(pos))
+        .filter_map(|&pos| grid.get_as(pos))
         .collect::<Vec<_>>()
         .into_boxed_slice()
 }
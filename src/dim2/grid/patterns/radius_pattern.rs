@@ -0,0 +1,26 @@
+use std::{cell::OnceCell, collections::BTreeMap, sync::Arc};
+
+use crate::{
+    dim2::grid::{patterns::GridPattern, position::DistanceMetric, Grid, Position},
+    FactoryCache,
+};
+
+/// every position within `radius` of the center according to `metric`, excluding the center itself
+pub fn radius_pattern(radius: usize, metric: DistanceMetric) -> Arc<GridPattern> {
+    type RadiusKey = (usize, DistanceMetric);
+    static mut PATTERN_CACHE: OnceCell<FactoryCache<RadiusKey, GridPattern, BTreeMap<RadiusKey, Arc<GridPattern>>>> = OnceCell::new();
+    #[allow(static_mut_refs)]
+    let cache = unsafe { PATTERN_CACHE.get_or_init(|| FactoryCache::new(BTreeMap::new(), Box::new(|(r, m)| new_radius_pattern(r, m)))) };
+    cache.get((radius, metric))
+}
+
+pub fn new_radius_pattern(radius: usize, metric: DistanceMetric) -> GridPattern {
+    let center = Position::new(radius as i64, radius as i64);
+
+    let grid_size = 2 * radius + 1;
+    let mut mapping = Grid::new(grid_size, grid_size, vec![false; grid_size * grid_size].into_boxed_slice());
+    for (pos, matches) in mapping.iter_mut_with_position() {
+        *matches = pos != center && metric.distance(pos, center) <= radius as f64;
+    }
+    GridPattern { mapping, center }
+}
@@ -6,9 +6,9 @@ use crate::{
 };
 
 pub fn rectangle_pattern(offset_left: usize, offset_up: usize, offset_right: usize, offset_down: usize) -> Arc<GridPattern> {
-    static mut PATTERN_CACHE: OnceCell<
-        FactoryCache<(usize, usize, usize, usize), GridPattern, BTreeMap<(usize, usize, usize, usize), Arc<GridPattern>>>,
-    > = OnceCell::new();
+    type RectangleOffsets = (usize, usize, usize, usize);
+    static mut PATTERN_CACHE: OnceCell<FactoryCache<RectangleOffsets, GridPattern, BTreeMap<RectangleOffsets, Arc<GridPattern>>>> =
+        OnceCell::new();
 
     let cache = unsafe {
         #[allow(static_mut_refs)]
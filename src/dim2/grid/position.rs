@@ -1,45 +1,203 @@
-use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::num::TryFromIntError;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
+use super::{Coordinate, Direction, RectSize, WrapMode};
+
+/// how continuous coordinates are mapped onto whole positions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingPolicy {
+    /// towards negative infinity, the tile containing the point
+    Floor,
+    /// to the nearest whole number, half way cases away from zero
+    Round,
+    /// towards positive infinity
+    Ceil,
+    /// towards zero
+    Truncate,
+}
+
+impl RoundingPolicy {
+    pub fn apply<C: Coordinate>(&self, value: f32) -> C {
+        let rounded = match self {
+            RoundingPolicy::Floor => value.floor(),
+            RoundingPolicy::Round => value.round(),
+            RoundingPolicy::Ceil => value.ceil(),
+            RoundingPolicy::Truncate => value.trunc(),
+        };
+        C::from_i64(rounded as i64)
+    }
+}
+
+/// position on a grid, generic over the integer type of its components
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Position {
-    pub x: i64,
-    pub y: i64,
+pub struct Position<C: Coordinate = i64> {
+    pub x: C,
+    pub y: C,
+}
+
+/// metric used to measure the distance between two positions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DistanceMetric {
+    /// straight line distance
+    Euclidean,
+    /// straight line distance without the square root, only useful for comparisons
+    EuclideanSquared,
+    /// amount of steps when only moving horizontally and vertically
+    Manhattan,
+    /// amount of steps when diagonal steps cost the same as straight ones
+    Chebyshev,
+    /// length of the path when diagonal steps cost `sqrt(2)`
+    Octile,
+}
+
+impl DistanceMetric {
+    pub fn distance<C: Coordinate>(&self, a: Position<C>, b: Position<C>) -> f64 {
+        match self {
+            DistanceMetric::Euclidean => distance(a, b),
+            DistanceMetric::EuclideanSquared => distance_squared(a, b) as f64,
+            DistanceMetric::Manhattan => manhattan_distance(a, b) as f64,
+            DistanceMetric::Chebyshev => chebyshev_distance(a, b) as f64,
+            DistanceMetric::Octile => octile_distance(a, b),
+        }
+    }
+
+    /// distance on a grid of the given size that continues on the opposite side on the axes of `wrap_mode`
+    pub fn wrapped_distance<C: Coordinate>(&self, a: Position<C>, b: Position<C>, size: RectSize, wrap_mode: WrapMode) -> f64 {
+        let (dx, dy) = wrap_mode.delta(a, b, size);
+        self.distance(Position::ZERO, Position::new(dx, dy))
+    }
+}
+
+/// absolute component differences, calculated as `i64` so small coordinate types cannot overflow
+fn abs_delta<C: Coordinate>(a: Position<C>, b: Position<C>) -> (i64, i64) {
+    ((a.x.to_i64() - b.x.to_i64()).abs(), (a.y.to_i64() - b.y.to_i64()).abs())
 }
 
-pub fn distance(a: Position, b: Position) -> f64 {
-    let vec = a - b;
-    f64::sqrt((vec.x.pow(2) + vec.y.pow(2)) as f64)
+pub fn distance<C: Coordinate>(a: Position<C>, b: Position<C>) -> f64 {
+    f64::sqrt(distance_squared(a, b) as f64)
+}
+
+/// this is cheaper than `distance` and is thus preferable for comparisons where the exact distance does not matter.
+pub fn distance_squared<C: Coordinate>(a: Position<C>, b: Position<C>) -> i64 {
+    let (dx, dy) = abs_delta(a, b);
+    dx.pow(2) + dy.pow(2)
+}
+
+pub fn manhattan_distance<C: Coordinate>(a: Position<C>, b: Position<C>) -> i64 {
+    let (dx, dy) = abs_delta(a, b);
+    dx + dy
+}
+
+pub fn chebyshev_distance<C: Coordinate>(a: Position<C>, b: Position<C>) -> i64 {
+    let (dx, dy) = abs_delta(a, b);
+    dx.max(dy)
+}
+
+pub fn octile_distance<C: Coordinate>(a: Position<C>, b: Position<C>) -> f64 {
+    let (dx, dy) = abs_delta(a, b);
+    let (straight, diagonal) = (dx.max(dy), dx.min(dy));
+    (straight - diagonal) as f64 + diagonal as f64 * std::f64::consts::SQRT_2
 }
 
 impl Position {
+    // only defined for the default coordinate type so `Position::ZERO` does not need a type annotation
     pub const ZERO: Position = Position { x: 0, y: 0 };
-    
-    pub const fn new(x: i64, y: i64) -> Self {
+}
+
+impl<C: Coordinate> Position<C> {
+    pub const fn new(x: C, y: C) -> Self {
         Position { x, y }
     }
 
-    pub fn distance(&self, other: Position) -> f64 {
+    /// converts into a position with a different coordinate type, `None` if a component does not fit
+    pub fn try_cast<D: Coordinate>(self) -> Option<Position<D>> {
+        Some(Position::new(D::try_from_i64(self.x.to_i64())?, D::try_from_i64(self.y.to_i64())?))
+    }
+
+    pub fn distance(&self, other: Position<C>) -> f64 {
         distance(*self, other)
     }
+
+    pub fn distance_squared(&self, other: Position<C>) -> i64 {
+        distance_squared(*self, other)
+    }
+
+    pub fn manhattan_distance(&self, other: Position<C>) -> i64 {
+        manhattan_distance(*self, other)
+    }
+
+    pub fn chebyshev_distance(&self, other: Position<C>) -> i64 {
+        chebyshev_distance(*self, other)
+    }
+
+    pub fn octile_distance(&self, other: Position<C>) -> f64 {
+        octile_distance(*self, other)
+    }
+
+    pub fn neighbor(&self, direction: Direction) -> Position<C> {
+        let offset = direction.offset();
+        Position::new(self.x + C::from_i64(offset.x), self.y + C::from_i64(offset.y))
+    }
+
+    /// the 4 orthogonally adjacent positions in the order of `Direction::CARDINAL`
+    pub fn neighbors4(&self) -> [Position<C>; 4] {
+        Direction::CARDINAL.map(|direction| self.neighbor(direction))
+    }
+
+    /// all 8 surrounding positions in the order of `Direction::ALL`
+    pub fn neighbors8(&self) -> [Position<C>; 8] {
+        Direction::ALL.map(|direction| self.neighbor(direction))
+    }
+
+    /// component-wise minimum
+    pub fn component_min(self, other: Position<C>) -> Position<C> {
+        Position::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    /// component-wise maximum
+    pub fn component_max(self, other: Position<C>) -> Position<C> {
+        Position::new(self.x.max(other.x), self.y.max(other.y))
+    }
+
+    pub fn abs(self) -> Position<C> {
+        Position::new(self.x.abs(), self.y.abs())
+    }
+
+    pub fn signum(self) -> Position<C> {
+        Position::new(self.x.signum(), self.y.signum())
+    }
+
+    /// closest position inside of a grid with the given size.
+    ///
+    /// panics if the size is empty.
+    pub fn clamp_to(self, size: RectSize) -> Position<C> {
+        assert!(!size.is_empty());
+        Position::new(
+            self.x.clamp(C::ZERO, C::from_usize(size.width - 1)),
+            self.y.clamp(C::ZERO, C::from_usize(size.height - 1)),
+        )
+    }
+
+    pub fn from_f32(x: f32, y: f32, rounding: RoundingPolicy) -> Position<C> {
+        Position::new(rounding.apply(x), rounding.apply(y))
+    }
+
+    pub fn to_f32(self) -> (f32, f32) {
+        (self.x.to_i64() as f32, self.y.to_i64() as f32)
+    }
 }
 
-impl From<(usize, usize)> for Position {
+impl<C: Coordinate> From<(usize, usize)> for Position<C> {
     fn from(position: (usize, usize)) -> Self {
         Position {
-            x: position.0 as i64,
-            y: position.1 as i64,
+            x: C::from_usize(position.0),
+            y: C::from_usize(position.1),
         }
     }
 }
 
-// impl Into<(usize, usize)> for Position {
-//     fn into(self) -> (usize, usize) {
-//         (self.x as usize, self.y as usize)
-//     }
-// }
-
-impl From<(i64, i64)> for Position {
-    fn from(position: (i64, i64)) -> Self {
+impl<C: Coordinate> From<(C, C)> for Position<C> {
+    fn from(position: (C, C)) -> Self {
         Position {
             x: position.0,
             y: position.1,
@@ -47,7 +205,22 @@ impl From<(i64, i64)> for Position {
     }
 }
 
-impl Add for Position {
+impl<C: Coordinate> TryFrom<Position<C>> for (usize, usize) {
+    type Error = TryFromIntError;
+
+    /// fails for negative coordinates
+    fn try_from(position: Position<C>) -> Result<Self, Self::Error> {
+        Ok((usize::try_from(position.x.to_i64())?, usize::try_from(position.y.to_i64())?))
+    }
+}
+
+impl<C: Coordinate> From<Position<C>> for (f32, f32) {
+    fn from(position: Position<C>) -> Self {
+        position.to_f32()
+    }
+}
+
+impl<C: Coordinate> Add for Position<C> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
@@ -55,7 +228,7 @@ impl Add for Position {
     }
 }
 
-impl Sub for Position {
+impl<C: Coordinate> Sub for Position<C> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
@@ -63,16 +236,55 @@ impl Sub for Position {
     }
 }
 
-impl AddAssign for Position {
+impl<C: Coordinate> AddAssign for Position<C> {
     fn add_assign(&mut self, rhs: Self) {
         self.x += rhs.x;
         self.y += rhs.y;
     }
 }
 
-impl SubAssign for Position {
+impl<C: Coordinate> SubAssign for Position<C> {
     fn sub_assign(&mut self, rhs: Self) {
         self.x -= rhs.x;
         self.y -= rhs.y;
     }
 }
+
+impl<C: Coordinate> Mul<C> for Position<C> {
+    type Output = Self;
+
+    fn mul(self, rhs: C) -> Self::Output {
+        Position::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<C: Coordinate> Div<C> for Position<C> {
+    type Output = Self;
+
+    /// divides both components, rounding towards zero like integer division
+    fn div(self, rhs: C) -> Self::Output {
+        Position::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+impl<C: Coordinate> MulAssign<C> for Position<C> {
+    fn mul_assign(&mut self, rhs: C) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+impl<C: Coordinate> DivAssign<C> for Position<C> {
+    fn div_assign(&mut self, rhs: C) {
+        self.x /= rhs;
+        self.y /= rhs;
+    }
+}
+
+impl<C: Coordinate> Neg for Position<C> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Position::new(-self.x, -self.y)
+    }
+}
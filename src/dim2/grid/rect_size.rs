@@ -1,7 +1,9 @@
-use super::position::Position;
+use std::{iter::FusedIterator, marker::PhantomData};
+
+use super::{grid_iter::SplitIterator, position::Position, to_grid_position_as, Coordinate, IterationOrder};
 
 /// logical, rectangular size in 2D space
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct RectSize {
     pub width: usize,
     pub height: usize,
@@ -21,46 +23,112 @@ impl RectSize {
         self.len() == 0
     }
 
-    pub fn iter(&self) -> SizeIter {
-        SizeIter {
-            size: self,
-            next_coords: Position { x: 0, y: 0 },
-        }
+    pub fn iter(&self) -> SizeIter<'_> {
+        SizeIter::new(self)
+    }
+
+    /// all positions in the given order
+    pub fn iter_ordered(&self, order: IterationOrder) -> std::vec::IntoIter<Position> {
+        order.positions(*self).into_iter()
+    }
+
+    /// like `iter_ordered`, but with positions of the given coordinate type
+    pub fn iter_ordered_as<C: Coordinate>(&self, order: IterationOrder) -> std::vec::IntoIter<Position<C>> {
+        order.positions(*self).into_iter()
+    }
+
+    /// like `iter`, but with positions of the given coordinate type
+    pub fn iter_as<C: Coordinate>(&self) -> SizeIter<'_, C> {
+        SizeIter::new(self)
     }
 }
 
 #[derive(Clone, Copy)]
-pub struct SizeIter<'a> {
+pub struct SizeIter<'a, C = i64>
+where
+    C: Coordinate,
+{
     size: &'a RectSize,
-    next_coords: Position,
+    /// index of the next position from the front
+    front: usize,
+    /// index after the next position from the back
+    back: usize,
+    coordinate: PhantomData<C>,
 }
 
-impl<'a> SizeIter<'a> {
+impl<'a, C> SizeIter<'a, C>
+where
+    C: Coordinate,
+{
     pub fn new(size: &'a RectSize) -> Self {
         SizeIter {
             size,
-            next_coords: Position { x: 0, y: 0 },
+            front: 0,
+            back: size.len(),
+            coordinate: PhantomData,
         }
     }
 }
 
-impl<'a> Iterator for SizeIter<'a> {
-    type Item = Position;
+impl<C> Iterator for SizeIter<'_, C>
+where
+    C: Coordinate,
+{
+    type Item = Position<C>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.next_coords.y as usize >= self.size.height {
+        if self.front == self.back {
             return None;
         }
 
-        let current_coords = self.next_coords;
+        let current = self.front;
+        self.front += 1;
+        Some(to_grid_position_as(current, *self.size))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.back - self.front, Some(self.back - self.front))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front += n.min(self.back - self.front);
+        self.next()
+    }
+}
 
-        if self.next_coords.x as usize >= self.size.width - 1 {
-            self.next_coords.y += 1;
-            self.next_coords.x = 0;
-        } else {
-            self.next_coords.x += 1;
+impl<C> DoubleEndedIterator for SizeIter<'_, C>
+where
+    C: Coordinate,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
         }
 
-        Some(current_coords)
+        self.back -= 1;
+        Some(to_grid_position_as(self.back, *self.size))
+    }
+}
+
+impl<C> ExactSizeIterator for SizeIter<'_, C>
+where
+    C: Coordinate,
+{
+}
+
+impl<C> FusedIterator for SizeIter<'_, C>
+where
+    C: Coordinate,
+{
+}
+
+impl<C> SplitIterator for SizeIter<'_, C>
+where
+    C: Coordinate,
+{
+    fn split_at(self, index: usize) -> (Self, Self) {
+        assert!(index <= self.len());
+        let middle = self.front + index;
+        (SizeIter { back: middle, ..self }, SizeIter { front: middle, ..self })
     }
 }
@@ -0,0 +1,59 @@
+use std::fmt;
+
+use super::{pathing::get_shortest_path, Grid, Position, ScreenTranslation};
+
+/// screen waypoints of a path together with its cost on the grid
+pub type ScreenPath = (Vec<(f32, f32)>, i64);
+
+/// reason why a path between two screen points could not be requested
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScreenPathError {
+    /// the start point lies outside of the `ScreenView`
+    StartOutOfView,
+    /// the end point lies outside of the `ScreenView`
+    EndOutOfView,
+}
+
+impl fmt::Display for ScreenPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StartOutOfView => write!(f, "path start is outside of the screen view"),
+            Self::EndOutOfView => write!(f, "path end is outside of the screen view"),
+        }
+    }
+}
+
+impl std::error::Error for ScreenPathError {}
+
+pub fn screen_path_exists<T: Copy>(
+    grid: &Grid<T>,
+    translation: &ScreenTranslation,
+    start: (f32, f32),
+    end: (f32, f32),
+    is_pathable_tile: &dyn Fn(T) -> bool,
+) -> Result<bool, ScreenPathError> {
+    Ok(get_shortest_screen_path(grid, translation, start, end, is_pathable_tile)?.is_some())
+}
+
+/// finds the shortest path between two screen points.
+///
+/// the waypoints are the screen centers of every tile along the path, including the tiles
+/// containing `start` and `end`. the cost is the amount of steps on the grid.
+pub fn get_shortest_screen_path<T: Copy>(
+    grid: &Grid<T>,
+    translation: &ScreenTranslation,
+    start: (f32, f32),
+    end: (f32, f32),
+    is_pathable_tile: &dyn Fn(T) -> bool,
+) -> Result<Option<ScreenPath>, ScreenPathError> {
+    let grid_start = translation.get_grid_position(start.0, start.1).ok_or(ScreenPathError::StartOutOfView)?;
+    let grid_end = translation.get_grid_position(end.0, end.1).ok_or(ScreenPathError::EndOutOfView)?;
+
+    Ok(get_shortest_path(grid, grid_start, grid_end, is_pathable_tile).map(|(path, cost)| {
+        let waypoints = path
+            .into_iter()
+            .map(|Position { x, y }| translation.block_center_to_screen_position(x, y))
+            .collect();
+        (waypoints, cost)
+    }))
+}
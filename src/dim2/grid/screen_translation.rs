@@ -1,11 +1,27 @@
 #![allow(dead_code)]
 
-use crate::{dim2::grid::{Position, RectSize}, ScreenView};
+use crate::{
+    dim2::grid::{GridRect, Position, RectSize, TileCorner, TileEdge, TilePoint},
+    ScreenView,
+};
+
+/// how the grid is scaled to the `ScreenView`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScalingMode {
+    /// the grid covers the whole screen view, tiles lose their aspect ratio
+    Stretch,
+    /// square tiles as large as possible while the whole grid stays visible (letterboxed)
+    Fit,
+    /// square tiles as small as possible while the grid covers the whole screen view, the overflow is cut off
+    Fill,
+}
 
 /// converts points inside a grid into their screen coordinates
 #[derive(Clone, Copy)]
 pub struct ScreenTranslation {
     screen_view: ScreenView,
+    /// screen area covered by the whole grid, may exceed `screen_view`
+    grid_view: ScreenView,
     grid_size: RectSize,
     tile_width: f32,
     tile_height: f32,
@@ -15,17 +31,102 @@ impl ScreenTranslation {
     pub fn new(screen_view: ScreenView, grid_size: RectSize) -> ScreenTranslation {
         ScreenTranslation {
             screen_view,
+            grid_view: screen_view,
             grid_size,
             tile_width: screen_view.width / grid_size.width as f32,
             tile_height: screen_view.height / grid_size.height as f32,
         }
     }
 
+    pub fn with_scaling(screen_view: ScreenView, grid_size: RectSize, scaling: ScalingMode) -> ScreenTranslation {
+        let horizontal_tile_size = screen_view.width / grid_size.width as f32;
+        let vertical_tile_size = screen_view.height / grid_size.height as f32;
+
+        match scaling {
+            ScalingMode::Stretch => ScreenTranslation::new(screen_view, grid_size),
+            ScalingMode::Fit => {
+                let tile_size = horizontal_tile_size.min(vertical_tile_size);
+                ScreenTranslation::with_tile_size(screen_view, grid_size, tile_size, tile_size)
+            }
+            ScalingMode::Fill => {
+                let tile_size = horizontal_tile_size.max(vertical_tile_size);
+                ScreenTranslation::with_tile_size(screen_view, grid_size, tile_size, tile_size)
+            }
+        }
+    }
+
+    /// uses a fixed pixel size per tile and centers the grid inside the screen view
+    pub fn with_tile_size(screen_view: ScreenView, grid_size: RectSize, tile_width: f32, tile_height: f32) -> ScreenTranslation {
+        let width = grid_size.width as f32 * tile_width;
+        let height = grid_size.height as f32 * tile_height;
+        let grid_view = ScreenView {
+            offset_x: screen_view.offset_x + (screen_view.width - width) / 2.,
+            offset_y: screen_view.offset_y + (screen_view.height - height) / 2.,
+            width,
+            height,
+        };
+
+        ScreenTranslation {
+            screen_view,
+            grid_view,
+            grid_size,
+            tile_width,
+            tile_height,
+        }
+    }
+
+    pub fn screen_view(&self) -> ScreenView {
+        self.screen_view
+    }
+
+    /// screen area covered by the whole grid, parts of it might lie outside of the screen view
+    pub fn grid_view(&self) -> ScreenView {
+        self.grid_view
+    }
+
+    /// part of the screen view that actually displays the grid
+    pub fn used_screen_view(&self) -> ScreenView {
+        self.screen_view.intersection(&self.grid_view).unwrap_or(ScreenView {
+            offset_x: self.screen_view.offset_x,
+            offset_y: self.screen_view.offset_y,
+            width: 0.,
+            height: 0.,
+        })
+    }
+
+    pub fn grid_size(&self) -> RectSize {
+        self.grid_size
+    }
+
+    pub fn tile_width(&self) -> f32 {
+        self.tile_width
+    }
+
+    pub fn tile_height(&self) -> f32 {
+        self.tile_height
+    }
+
+    /// converts a screen point into fractional grid coordinates without any bounds checks
+    pub fn screen_to_grid_coordinates(&self, screen_x: f32, screen_y: f32) -> (f32, f32) {
+        (
+            (screen_x - self.grid_view.offset_x) / self.tile_width,
+            (screen_y - self.grid_view.offset_y) / self.tile_height,
+        )
+    }
+
+    /// converts fractional grid coordinates into a screen point without any bounds checks
+    pub fn grid_to_screen_coordinates(&self, grid_x: f32, grid_y: f32) -> (f32, f32) {
+        (
+            self.grid_view.offset_x + grid_x * self.tile_width,
+            self.grid_view.offset_y + grid_y * self.tile_height,
+        )
+    }
+
     pub fn get_grid_position_x(&self, screen_x: f32) -> Option<i64> {
         if !self.in_screen_bounds_horizontally(screen_x) {
             return None;
         }
-        let logic_x = (screen_x - self.screen_view.offset_x) / self.tile_width;
+        let logic_x = (screen_x - self.grid_view.offset_x) / self.tile_width;
         Some(logic_x as i64)
     }
 
@@ -33,16 +134,18 @@ impl ScreenTranslation {
         if !self.in_screen_bounds_vertically(screen_y) {
             return None;
         }
-        let logic_y = (screen_y - self.screen_view.offset_y) / self.tile_height;
+        let logic_y = (screen_y - self.grid_view.offset_y) / self.tile_height;
         Some(logic_y as i64)
     }
 
     fn in_screen_bounds_horizontally(&self, screen_x: f32) -> bool {
         (self.screen_view.offset_x..self.screen_view.offset_x + self.screen_view.width).contains(&screen_x)
+            && (self.grid_view.offset_x..self.grid_view.offset_x + self.grid_view.width).contains(&screen_x)
     }
 
     fn in_screen_bounds_vertically(&self, screen_y: f32) -> bool {
         (self.screen_view.offset_y..self.screen_view.offset_y + self.screen_view.height).contains(&screen_y)
+            && (self.grid_view.offset_y..self.grid_view.offset_y + self.grid_view.height).contains(&screen_y)
     }
 
     pub fn get_grid_position(&self, screen_x: f32, screen_y: f32) -> Option<Position> {
@@ -57,10 +160,97 @@ impl ScreenTranslation {
     }
 
     pub fn horizontal_center_to_pixel(&self, x: i64) -> f32 {
-        self.screen_view.offset_x + (x as f32 * self.tile_width) + (self.tile_width / 2.)
+        self.grid_view.offset_x + (x as f32 * self.tile_width) + (self.tile_width / 2.)
     }
 
     pub fn vertical_center_to_pixel(&self, y: i64) -> f32 {
-        self.screen_view.offset_y + (y as f32 * self.tile_height) + (self.tile_height / 2.)
+        self.grid_view.offset_y + (y as f32 * self.tile_height) + (self.tile_height / 2.)
+    }
+
+    /// tile below the screen point together with the location of the point inside of that tile
+    pub fn get_tile_point(&self, screen_x: f32, screen_y: f32) -> Option<TilePoint> {
+        let position = self.get_grid_position(screen_x, screen_y)?;
+        let (grid_x, grid_y) = self.screen_to_grid_coordinates(screen_x, screen_y);
+
+        Some(TilePoint {
+            position,
+            local_x: (grid_x - position.x as f32).clamp(0., 1.),
+            local_y: (grid_y - position.y as f32).clamp(0., 1.),
+        })
+    }
+
+    pub fn get_nearest_edge(&self, screen_x: f32, screen_y: f32) -> Option<(Position, TileEdge)> {
+        let tile_point = self.get_tile_point(screen_x, screen_y)?;
+        Some((tile_point.position, tile_point.nearest_edge(self.tile_width, self.tile_height)))
+    }
+
+    pub fn get_nearest_corner(&self, screen_x: f32, screen_y: f32) -> Option<(Position, TileCorner)> {
+        let tile_point = self.get_tile_point(screen_x, screen_y)?;
+        Some((tile_point.position, tile_point.nearest_corner()))
+    }
+
+    /// start and end point of a tile edge on screen
+    pub fn edge_to_screen_line(&self, position: Position, edge: TileEdge) -> ((f32, f32), (f32, f32)) {
+        let (start, end) = TileEdge::grid_coordinates(position, edge);
+        (
+            self.grid_to_screen_coordinates(start.0, start.1),
+            self.grid_to_screen_coordinates(end.0, end.1),
+        )
+    }
+
+    pub fn edge_center_to_screen_position(&self, position: Position, edge: TileEdge) -> (f32, f32) {
+        let ((start_x, start_y), (end_x, end_y)) = self.edge_to_screen_line(position, edge);
+        ((start_x + end_x) / 2., (start_y + end_y) / 2.)
     }
+
+    pub fn corner_to_screen_position(&self, position: Position, corner: TileCorner) -> (f32, f32) {
+        let (x, y) = TileCorner::grid_coordinates(position, corner);
+        self.grid_to_screen_coordinates(x, y)
+    }
+
+    /// grid region of every tile touched by the screen rectangle.
+    ///
+    /// the rectangle is clamped to the displayed part of the grid, `None` if nothing of it remains.
+    pub fn get_grid_region(&self, screen_rect: ScreenView) -> Option<GridRect> {
+        let used = self.used_screen_view();
+        let (left, right) = clamp_to_view(screen_rect.offset_x, screen_rect.width, used.offset_x, used.width)?;
+        let (top, bottom) = clamp_to_view(screen_rect.offset_y, screen_rect.height, used.offset_y, used.height)?;
+        let (left, top) = self.screen_to_grid_coordinates(left, top);
+        let (right, bottom) = self.screen_to_grid_coordinates(right, bottom);
+
+        let left = (left.floor() as i64).clamp(0, self.grid_size.width as i64 - 1);
+        let top = (top.floor() as i64).clamp(0, self.grid_size.height as i64 - 1);
+        // a rectangle always covers at least the tile it starts in
+        let right = (right.ceil() as i64).clamp(left + 1, self.grid_size.width as i64);
+        let bottom = (bottom.ceil() as i64).clamp(top + 1, self.grid_size.height as i64);
+
+        Some(GridRect::new(
+            Position::new(left, top),
+            RectSize::new((right - left) as usize, (bottom - top) as usize),
+        ))
+    }
+
+    /// screen rectangle covered by a grid region, not clamped to the screen view
+    pub fn grid_region_to_screen(&self, region: GridRect) -> ScreenView {
+        let (offset_x, offset_y) = self.grid_to_screen_coordinates(region.offset.x as f32, region.offset.y as f32);
+        ScreenView {
+            offset_x,
+            offset_y,
+            width: region.size.width as f32 * self.tile_width,
+            height: region.size.height as f32 * self.tile_height,
+        }
+    }
+}
+
+/// start and end of a rectangle side clamped to the view, `None` if it lies outside.
+///
+/// the end of the view is exclusive, so a side without length, e.g. of a click, only counts if it starts before it.
+fn clamp_to_view(start: f32, length: f32, view_start: f32, view_length: f32) -> Option<(f32, f32)> {
+    let view_end = view_start + view_length;
+    if length == 0. {
+        return (view_start..view_end).contains(&start).then_some((start, start));
+    }
+
+    let (start, end) = (start.max(view_start), (start + length).min(view_end));
+    (start < end).then_some((start, end))
 }
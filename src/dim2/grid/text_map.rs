@@ -0,0 +1,98 @@
+use std::fmt;
+
+use super::{Grid, Position};
+
+/// reason why a text could not be parsed into a grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextMapError {
+    /// the text does not contain any row
+    Empty,
+    /// a row has a different amount of characters than the first row
+    RaggedRow { row: usize, expected_width: usize, width: usize },
+    /// the mapping closure does not know a character
+    UnknownChar { character: char, position: Position },
+}
+
+impl fmt::Display for TextMapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "text map does not contain any row"),
+            Self::RaggedRow { row, expected_width, width } => {
+                write!(f, "row {} has {} characters, but the rows before have {}", row, width, expected_width)
+            }
+            Self::UnknownChar { character, position } => {
+                write!(f, "unknown character '{}' at ({}, {})", character, position.x, position.y)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TextMapError {}
+
+impl<T> Grid<T>
+where
+    T: Copy,
+{
+    /// parses one row per line and one value per character, `to_tile` returns `None` for unknown characters.
+    ///
+    /// empty or whitespace only lines at the start and end are ignored and the indentation shared by all rows is
+    /// removed, so maps can be written inside of indented raw strings. because of that, a whitespace tile at the start
+    /// of every row is removed as well.
+    pub fn from_text<F>(text: &str, mut to_tile: F) -> Result<Grid<T>, TextMapError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let lines: Vec<_> = text.lines().collect();
+        let is_blank = |line: &&str| line.trim().is_empty();
+        let first = lines.iter().position(|line| !is_blank(line)).ok_or(TextMapError::Empty)?;
+        let last = lines.iter().rposition(|line| !is_blank(line)).unwrap_or(first);
+
+        let rows = &lines[first..=last];
+        let indent = rows
+            .iter()
+            .filter(|row| !is_blank(row))
+            .map(|row| row.chars().take_while(|character| character.is_whitespace()).count())
+            .min()
+            .unwrap_or(0);
+        let row_chars = |row: &str| row.chars().skip(indent).collect::<Vec<_>>();
+
+        let width = row_chars(rows[0]).len();
+        let mut values = Vec::with_capacity(width * rows.len());
+        for (y, row) in rows.iter().enumerate() {
+            let row = row_chars(row);
+            let row_width = row.len();
+            if row_width != width {
+                return Err(TextMapError::RaggedRow {
+                    row: y,
+                    expected_width: width,
+                    width: row_width,
+                });
+            }
+
+            for (x, character) in row.into_iter().enumerate() {
+                let tile = to_tile(character).ok_or(TextMapError::UnknownChar {
+                    character,
+                    position: Position::new(x as i64, y as i64),
+                })?;
+                values.push(tile);
+            }
+        }
+
+        Ok(Grid::new(width, rows.len(), values.into_boxed_slice()))
+    }
+
+    /// renders one line per row and one character per value, without a newline after the last row
+    pub fn to_text<F>(&self, mut to_char: F) -> String
+    where
+        F: FnMut(T) -> char,
+    {
+        let mut text = String::with_capacity(self.len() + self.height());
+        for (pos, &value) in self.iter_with_position() {
+            if pos.x == 0 && pos.y > 0 {
+                text.push('\n');
+            }
+            text.push(to_char(value));
+        }
+        text
+    }
+}
@@ -0,0 +1,94 @@
+use super::Position;
+
+/// a point inside of a tile
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TilePoint {
+    pub position: Position,
+    /// horizontal location inside the tile from 0 (left) to 1 (right)
+    pub local_x: f32,
+    /// vertical location inside the tile from 0 (top) to 1 (bottom)
+    pub local_y: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TileEdge {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TileCorner {
+    TopLeft,
+    TopRight,
+    BottomRight,
+    BottomLeft,
+}
+
+impl TilePoint {
+    /// edge closest to the point, `tile_width` and `tile_height` weigh the distances for non-square tiles
+    pub fn nearest_edge(&self, tile_width: f32, tile_height: f32) -> TileEdge {
+        let distances = [
+            (TileEdge::Top, self.local_y * tile_height),
+            (TileEdge::Right, (1. - self.local_x) * tile_width),
+            (TileEdge::Bottom, (1. - self.local_y) * tile_height),
+            (TileEdge::Left, self.local_x * tile_width),
+        ];
+
+        distances
+            .into_iter()
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(edge, _)| edge)
+            .unwrap()
+    }
+
+    pub fn nearest_corner(&self) -> TileCorner {
+        match (self.local_x < 0.5, self.local_y < 0.5) {
+            (true, true) => TileCorner::TopLeft,
+            (false, true) => TileCorner::TopRight,
+            (false, false) => TileCorner::BottomRight,
+            (true, false) => TileCorner::BottomLeft,
+        }
+    }
+}
+
+impl TileEdge {
+    /// every edge is shared by two tiles, this returns the `Top` or `Left` edge representing the same line
+    pub fn normalize(position: Position, edge: TileEdge) -> (Position, TileEdge) {
+        match edge {
+            TileEdge::Right => (position + Position::new(1, 0), TileEdge::Left),
+            TileEdge::Bottom => (position + Position::new(0, 1), TileEdge::Top),
+            _ => (position, edge),
+        }
+    }
+
+    /// start and end of the edge in grid coordinates, in clockwise order
+    pub fn grid_coordinates(position: Position, edge: TileEdge) -> ((f32, f32), (f32, f32)) {
+        let (start, end) = match edge {
+            TileEdge::Top => (TileCorner::TopLeft, TileCorner::TopRight),
+            TileEdge::Right => (TileCorner::TopRight, TileCorner::BottomRight),
+            TileEdge::Bottom => (TileCorner::BottomRight, TileCorner::BottomLeft),
+            TileEdge::Left => (TileCorner::BottomLeft, TileCorner::TopLeft),
+        };
+        (TileCorner::grid_coordinates(position, start), TileCorner::grid_coordinates(position, end))
+    }
+}
+
+impl TileCorner {
+    /// every corner is shared by up to four tiles, this returns the `TopLeft` corner representing the same point
+    pub fn normalize(position: Position, corner: TileCorner) -> (Position, TileCorner) {
+        let offset = match corner {
+            TileCorner::TopLeft => Position::new(0, 0),
+            TileCorner::TopRight => Position::new(1, 0),
+            TileCorner::BottomRight => Position::new(1, 1),
+            TileCorner::BottomLeft => Position::new(0, 1),
+        };
+        (position + offset, TileCorner::TopLeft)
+    }
+
+    pub fn grid_coordinates(position: Position, corner: TileCorner) -> (f32, f32) {
+        let (Position { x, y }, _) = TileCorner::normalize(position, corner);
+        (x as f32, y as f32)
+    }
+}
@@ -0,0 +1,80 @@
+use super::{Position, ScreenTranslation};
+
+/// manages several named viewports onto grids, e.g. for split-screen or minimaps.
+///
+/// viewports are layered in insertion order, a viewport inserted later is drawn on top of the earlier ones.
+pub struct ViewportManager<K>
+where
+    K: PartialEq,
+{
+    viewports: Vec<(K, ScreenTranslation)>,
+}
+
+impl<K> Default for ViewportManager<K>
+where
+    K: PartialEq,
+{
+    fn default() -> Self {
+        Self { viewports: vec![] }
+    }
+}
+
+impl<K> ViewportManager<K>
+where
+    K: PartialEq,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// adds a viewport on top of all others, an existing viewport with the same name is replaced in place
+    pub fn insert(&mut self, name: K, translation: ScreenTranslation) {
+        match self.get_mut(&name) {
+            Some(existing) => *existing = translation,
+            None => self.viewports.push((name, translation)),
+        }
+    }
+
+    pub fn remove(&mut self, name: &K) -> Option<ScreenTranslation> {
+        let index = self.viewports.iter().position(|(key, _)| key == name)?;
+        Some(self.viewports.remove(index).1)
+    }
+
+    pub fn get(&self, name: &K) -> Option<&ScreenTranslation> {
+        self.viewports.iter().find(|(key, _)| key == name).map(|(_, translation)| translation)
+    }
+
+    pub fn get_mut(&mut self, name: &K) -> Option<&mut ScreenTranslation> {
+        self.viewports.iter_mut().find(|(key, _)| key == name).map(|(_, translation)| translation)
+    }
+
+    pub fn len(&self) -> usize {
+        self.viewports.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.viewports.is_empty()
+    }
+
+    /// all viewports from bottom to top
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &ScreenTranslation)> {
+        self.viewports.iter().map(|(key, translation)| (key, translation))
+    }
+
+    /// topmost viewport whose screen view contains the point
+    pub fn viewport_at(&self, screen_x: f32, screen_y: f32) -> Option<(&K, &ScreenTranslation)> {
+        self.viewports
+            .iter()
+            .rev()
+            .find(|(_, translation)| translation.screen_view().contains_point(screen_x, screen_y))
+            .map(|(key, translation)| (key, translation))
+    }
+
+    /// grid position below the point in the topmost viewport containing it.
+    ///
+    /// returns the viewport name with `None` if the point hits a part of the viewport not covered by the grid.
+    pub fn get_grid_position(&self, screen_x: f32, screen_y: f32) -> Option<(&K, Option<Position>)> {
+        let (name, translation) = self.viewport_at(screen_x, screen_y)?;
+        Some((name, translation.get_grid_position(screen_x, screen_y)))
+    }
+}
@@ -0,0 +1,99 @@
+use super::{patterns::PatternPositions, Coordinate, Grid, Position, RectSize};
+
+/// axes on which a grid continues on the opposite side instead of ending at its border
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WrapMode {
+    /// positions outside of the grid do not exist
+    #[default]
+    None,
+    /// leaving on the left or right enters on the other side
+    Horizontal,
+    /// leaving on the top or bottom enters on the other side
+    Vertical,
+    /// the grid is a torus
+    Both,
+}
+
+impl WrapMode {
+    pub const fn wraps_x(&self) -> bool {
+        matches!(self, WrapMode::Horizontal | WrapMode::Both)
+    }
+
+    pub const fn wraps_y(&self) -> bool {
+        matches!(self, WrapMode::Vertical | WrapMode::Both)
+    }
+
+    /// position inside a grid of the given size that `position` refers to, `None` if it is outside on a non wrapping axis
+    pub fn wrap<C: Coordinate>(&self, position: Position<C>, size: RectSize) -> Option<Position<C>> {
+        let x = wrap_component(position.x.to_i64(), size.width as i64, self.wraps_x())?;
+        let y = wrap_component(position.y.to_i64(), size.height as i64, self.wraps_y())?;
+        Some(Position::new(C::from_i64(x), C::from_i64(y)))
+    }
+
+    /// shortest component differences from `a` to `b`, going across the border on wrapping axes if that is shorter.
+    ///
+    /// the result can be added to `a` and wrapped to end up on `b`.
+    pub fn delta<C: Coordinate>(&self, a: Position<C>, b: Position<C>, size: RectSize) -> (i64, i64) {
+        (
+            shortest_delta(b.x.to_i64() - a.x.to_i64(), size.width as i64, self.wraps_x()),
+            shortest_delta(b.y.to_i64() - a.y.to_i64(), size.height as i64, self.wraps_y()),
+        )
+    }
+}
+
+fn wrap_component(value: i64, length: i64, wraps: bool) -> Option<i64> {
+    if wraps && length > 0 {
+        Some(value.rem_euclid(length))
+    } else if (0..length).contains(&value) {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+fn shortest_delta(delta: i64, length: i64, wraps: bool) -> i64 {
+    if !wraps || length == 0 {
+        return delta;
+    }
+
+    let delta = delta.rem_euclid(length);
+    if delta * 2 > length {
+        delta - length
+    } else {
+        delta
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Copy,
+{
+    /// like `get`, but positions outside of the grid continue on the opposite side on the axes of `wrap_mode`
+    pub fn get_wrapped<C: Coordinate>(&self, position: Position<C>, wrap_mode: WrapMode) -> Option<T> {
+        self.get_as(wrap_mode.wrap(position, self.size())?)
+    }
+
+    pub fn get_mut_wrapped<C: Coordinate>(&mut self, position: Position<C>, wrap_mode: WrapMode) -> Option<&mut T> {
+        let position = wrap_mode.wrap(position, self.size())?;
+        self.get_mut_as(position)
+    }
+}
+
+/// like `get_grid_values_from_pattern`, but pattern positions outside of the grid wrap according to `wrap_mode`
+pub fn get_wrapped_grid_values_from_pattern<T, C>(
+    grid: &Grid<T>,
+    center: Position<C>,
+    pattern: &dyn PatternPositions<C>,
+    wrap_mode: WrapMode,
+) -> Box<[T]>
+where
+    T: Copy,
+    C: Coordinate,
+{
+    pattern
+        .get_pattern_positions(center)
+        .iter()
+        .filter_map(|&pos| grid.get_wrapped(pos, wrap_mode))
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+}
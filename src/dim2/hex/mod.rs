@@ -0,0 +1,129 @@
+use std::ops::{Index, IndexMut};
+use std::slice::{Iter, IterMut};
+
+use super::grid::RectSize;
+
+pub mod pathing;
+pub mod patterns;
+pub mod position;
+pub use position::HexPosition;
+pub mod screen_translation;
+pub use screen_translation::*;
+
+/// stores a value for every hexagon of a parallelogram shaped map.
+///
+/// valid positions have `0 <= q < width` and `0 <= r < height`.
+#[derive(Clone)]
+pub struct HexGrid<T>
+where
+    T: Copy,
+{
+    size: RectSize,
+    values: Box<[T]>,
+}
+
+impl<T> HexGrid<T>
+where
+    T: Copy,
+{
+    pub const fn new(width: usize, height: usize, values: Box<[T]>) -> Self {
+        assert!(values.len() == height * width);
+        Self {
+            size: RectSize { width, height },
+            values,
+        }
+    }
+
+    pub const fn size(&self) -> RectSize {
+        self.size
+    }
+
+    pub const fn width(&self) -> usize {
+        self.size.width
+    }
+
+    pub const fn height(&self) -> usize {
+        self.size.height
+    }
+
+    pub const fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, position: HexPosition) -> Option<T> {
+        if self.in_bounds(position) {
+            Some(self.values[self.to_index(position)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: HexPosition) -> Option<&mut T> {
+        if self.in_bounds(position) {
+            let index = self.to_index(position);
+            Some(&mut self.values[index])
+        } else {
+            None
+        }
+    }
+
+    pub fn into_array(self) -> Box<[T]> {
+        self.values
+    }
+
+    pub fn iter_with_position(&self) -> impl Iterator<Item = (HexPosition, &T)> {
+        let width = self.size.width;
+        self.values
+            .iter()
+            .enumerate()
+            .map(move |(index, value)| (HexPosition::new((index % width) as i64, (index / width) as i64), value))
+    }
+
+    pub fn iter_mut_with_position(&mut self) -> impl Iterator<Item = (HexPosition, &mut T)> {
+        let width = self.size.width;
+        self.values
+            .iter_mut()
+            .enumerate()
+            .map(move |(index, value)| (HexPosition::new((index % width) as i64, (index / width) as i64), value))
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.values.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.values.iter_mut()
+    }
+
+    pub const fn in_bounds(&self, position: HexPosition) -> bool {
+        position.q >= 0 && position.r >= 0 && (position.q as usize) < self.size.width && (position.r as usize) < self.size.height
+    }
+
+    const fn to_index(&self, position: HexPosition) -> usize {
+        position.r as usize * self.size.width + position.q as usize
+    }
+}
+
+impl<T> Index<HexPosition> for HexGrid<T>
+where
+    T: Copy,
+{
+    type Output = T;
+    fn index(&self, index: HexPosition) -> &Self::Output {
+        &self.values[self.to_index(index)]
+    }
+}
+
+impl<T> IndexMut<HexPosition> for HexGrid<T>
+where
+    T: Copy,
+{
+    fn index_mut(&mut self, index: HexPosition) -> &mut Self::Output {
+        let index = self.to_index(index);
+        &mut self.values[index]
+    }
+}
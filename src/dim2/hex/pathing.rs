@@ -0,0 +1,35 @@
+use pathfinding::prelude::astar;
+
+use super::{position::hex_distance, HexGrid, HexPosition};
+
+pub fn path_exists<T: Copy>(grid: &HexGrid<T>, start: HexPosition, end: HexPosition, is_pathable_tile: &dyn Fn(T) -> bool) -> bool {
+    get_shortest_path(grid, start, end, is_pathable_tile).is_some()
+}
+
+pub fn get_shortest_path<T: Copy>(
+    grid: &HexGrid<T>,
+    start: HexPosition,
+    end: HexPosition,
+    is_pathable_tile: &dyn Fn(T) -> bool,
+) -> Option<(Vec<HexPosition>, i64)> {
+    astar(
+        &start,
+        |node| get_neighbors(grid, *node, is_pathable_tile),
+        |node| hex_distance(*node, end),
+        |node| *node == end,
+    )
+}
+
+fn get_neighbors<T: Copy>(grid: &HexGrid<T>, node: HexPosition, is_pathable_tile: &dyn Fn(T) -> bool) -> Vec<(HexPosition, i64)> {
+    node.neighbors()
+        .into_iter()
+        .filter_map(|pos| {
+            let entity = grid.get(pos)?;
+            if is_pathable_tile(entity) {
+                Some((pos, 1))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
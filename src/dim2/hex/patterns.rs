@@ -0,0 +1,69 @@
+use super::{HexGrid, HexPosition};
+
+pub trait HexPatternPositions {
+    fn get_pattern_positions(&self, center: HexPosition) -> Box<[HexPosition]>;
+}
+
+/// the six hexagons touching the center
+pub struct NeighborPattern;
+
+impl HexPatternPositions for NeighborPattern {
+    fn get_pattern_positions(&self, center: HexPosition) -> Box<[HexPosition]> {
+        Box::new(center.neighbors())
+    }
+}
+
+/// all hexagons with exactly `radius` distance to the center
+pub struct RingPattern {
+    pub radius: usize,
+}
+
+impl HexPatternPositions for RingPattern {
+    fn get_pattern_positions(&self, center: HexPosition) -> Box<[HexPosition]> {
+        hex_ring(center, self.radius).into_boxed_slice()
+    }
+}
+
+/// all hexagons within `radius` distance of the center, ordered ring by ring starting at the center
+pub struct SpiralPattern {
+    pub radius: usize,
+}
+
+impl HexPatternPositions for SpiralPattern {
+    fn get_pattern_positions(&self, center: HexPosition) -> Box<[HexPosition]> {
+        let mut result = vec![center];
+        for radius in 1..=self.radius {
+            result.append(&mut hex_ring(center, radius));
+        }
+        result.into_boxed_slice()
+    }
+}
+
+fn hex_ring(center: HexPosition, radius: usize) -> Vec<HexPosition> {
+    if radius == 0 {
+        return vec![center];
+    }
+
+    let mut result = Vec::with_capacity(6 * radius);
+    // start at the corner in direction 4 and walk along each side of the ring
+    let mut current = center + HexPosition::DIRECTIONS[4].scale(radius as i64);
+    for direction in 0..6 {
+        for _ in 0..radius {
+            result.push(current);
+            current = current.neighbor(direction);
+        }
+    }
+    result
+}
+
+pub fn get_hex_grid_values_from_pattern<T>(grid: &HexGrid<T>, center: HexPosition, pattern: &dyn HexPatternPositions) -> Box<[T]>
+where
+    T: Copy,
+{
+    pattern
+        .get_pattern_positions(center)
+        .iter()
+        .filter_map(|&pos| grid.get(pos))
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+}
@@ -0,0 +1,126 @@
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// position of a hexagon in axial coordinates.
+///
+/// the third cube coordinate is implied by `q + r + s == 0`, see `HexPosition::s`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HexPosition {
+    pub q: i64,
+    pub r: i64,
+}
+
+/// amount of hexagons between `a` and `b`
+pub fn hex_distance(a: HexPosition, b: HexPosition) -> i64 {
+    let vec = a - b;
+    (vec.q.abs() + vec.r.abs() + vec.s().abs()) / 2
+}
+
+impl HexPosition {
+    pub const ZERO: HexPosition = HexPosition { q: 0, r: 0 };
+
+    /// unit offsets to the six neighbors, counter clockwise starting at the positive `q` axis
+    pub const DIRECTIONS: [HexPosition; 6] = [
+        HexPosition { q: 1, r: 0 },
+        HexPosition { q: 1, r: -1 },
+        HexPosition { q: 0, r: -1 },
+        HexPosition { q: -1, r: 0 },
+        HexPosition { q: -1, r: 1 },
+        HexPosition { q: 0, r: 1 },
+    ];
+
+    pub const fn new(q: i64, r: i64) -> Self {
+        HexPosition { q, r }
+    }
+
+    pub const fn from_cube(q: i64, r: i64, s: i64) -> Self {
+        assert!(q + r + s == 0);
+        HexPosition { q, r }
+    }
+
+    /// the implied third cube coordinate
+    pub const fn s(&self) -> i64 {
+        -self.q - self.r
+    }
+
+    pub const fn to_cube(self) -> (i64, i64, i64) {
+        (self.q, self.r, self.s())
+    }
+
+    /// rounds fractional axial coordinates to the hexagon containing them
+    pub fn round(q: f32, r: f32) -> Self {
+        let s = -q - r;
+        let mut rounded_q = q.round();
+        let mut rounded_r = r.round();
+        let rounded_s = s.round();
+
+        let diff_q = (rounded_q - q).abs();
+        let diff_r = (rounded_r - r).abs();
+        let diff_s = (rounded_s - s).abs();
+
+        // the coordinate with the largest rounding error is reconstructed from the other two
+        if diff_q > diff_r && diff_q > diff_s {
+            rounded_q = -rounded_r - rounded_s;
+        } else if diff_r > diff_s {
+            rounded_r = -rounded_q - rounded_s;
+        }
+
+        HexPosition::new(rounded_q as i64, rounded_r as i64)
+    }
+
+    pub fn distance(&self, other: HexPosition) -> i64 {
+        hex_distance(*self, other)
+    }
+
+    /// neighbor in the given direction, see `HexPosition::DIRECTIONS`
+    pub fn neighbor(&self, direction: usize) -> HexPosition {
+        *self + HexPosition::DIRECTIONS[direction % 6]
+    }
+
+    pub fn neighbors(&self) -> [HexPosition; 6] {
+        HexPosition::DIRECTIONS.map(|direction| *self + direction)
+    }
+
+    /// multiplies both coordinates by `factor`
+    pub const fn scale(self, factor: i64) -> Self {
+        HexPosition::new(self.q * factor, self.r * factor)
+    }
+}
+
+impl From<(i64, i64)> for HexPosition {
+    fn from(position: (i64, i64)) -> Self {
+        HexPosition {
+            q: position.0,
+            r: position.1,
+        }
+    }
+}
+
+impl Add for HexPosition {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        HexPosition::new(self.q + rhs.q, self.r + rhs.r)
+    }
+}
+
+impl Sub for HexPosition {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        HexPosition::new(self.q - rhs.q, self.r - rhs.r)
+    }
+}
+
+impl AddAssign for HexPosition {
+    fn add_assign(&mut self, rhs: Self) {
+        self.q += rhs.q;
+        self.r += rhs.r;
+    }
+}
+
+impl SubAssign for HexPosition {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.q -= rhs.q;
+        self.r -= rhs.r;
+    }
+}
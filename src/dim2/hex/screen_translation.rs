@@ -0,0 +1,92 @@
+use crate::ScreenView;
+
+use super::HexPosition;
+
+const SQRT_3: f32 = 1.732_050_8;
+
+/// rotation of the hexagons on screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HexOrientation {
+    /// hexagons have a corner at the top, rows are horizontal
+    PointyTop,
+    /// hexagons have an edge at the top, columns are vertical
+    FlatTop,
+}
+
+/// converts hexagons into their screen coordinates and back.
+///
+/// the hexagon at `HexPosition::ZERO` touches the top left corner of the `ScreenView`.
+#[derive(Clone, Copy)]
+pub struct HexScreenTranslation {
+    screen_view: ScreenView,
+    orientation: HexOrientation,
+    hex_size: f32,
+}
+
+impl HexScreenTranslation {
+    /// `hex_size` is the distance from the center of a hexagon to any of its corners
+    pub fn new(screen_view: ScreenView, orientation: HexOrientation, hex_size: f32) -> HexScreenTranslation {
+        HexScreenTranslation {
+            screen_view,
+            orientation,
+            hex_size,
+        }
+    }
+
+    pub fn orientation(&self) -> HexOrientation {
+        self.orientation
+    }
+
+    pub fn hex_size(&self) -> f32 {
+        self.hex_size
+    }
+
+    /// width and height of a single hexagon on screen
+    pub fn hex_dimensions(&self) -> (f32, f32) {
+        match self.orientation {
+            HexOrientation::PointyTop => (SQRT_3 * self.hex_size, 2. * self.hex_size),
+            HexOrientation::FlatTop => (2. * self.hex_size, SQRT_3 * self.hex_size),
+        }
+    }
+
+    pub fn get_hex_position(&self, screen_x: f32, screen_y: f32) -> Option<HexPosition> {
+        if !self.in_screen_bounds(screen_x, screen_y) {
+            return None;
+        }
+
+        let (origin_x, origin_y) = self.origin();
+        let x = (screen_x - origin_x) / self.hex_size;
+        let y = (screen_y - origin_y) / self.hex_size;
+
+        let (q, r) = match self.orientation {
+            HexOrientation::PointyTop => (SQRT_3 / 3. * x - y / 3., 2. / 3. * y),
+            HexOrientation::FlatTop => (2. / 3. * x, -x / 3. + SQRT_3 / 3. * y),
+        };
+
+        Some(HexPosition::round(q, r))
+    }
+
+    pub fn hex_center_to_screen_position(&self, position: HexPosition) -> (f32, f32) {
+        let q = position.q as f32;
+        let r = position.r as f32;
+
+        let (x, y) = match self.orientation {
+            HexOrientation::PointyTop => (SQRT_3 * q + SQRT_3 / 2. * r, 1.5 * r),
+            HexOrientation::FlatTop => (1.5 * q, SQRT_3 / 2. * q + SQRT_3 * r),
+        };
+
+        let (origin_x, origin_y) = self.origin();
+        (origin_x + x * self.hex_size, origin_y + y * self.hex_size)
+    }
+
+    /// screen position of the center of `HexPosition::ZERO`
+    fn origin(&self) -> (f32, f32) {
+        let (width, height) = self.hex_dimensions();
+        (self.screen_view.offset_x + width / 2., self.screen_view.offset_y + height / 2.)
+    }
+
+    fn in_screen_bounds(&self, screen_x: f32, screen_y: f32) -> bool {
+        (self.screen_view.offset_x..self.screen_view.offset_x + self.screen_view.width).contains(&screen_x)
+            && (self.screen_view.offset_y..self.screen_view.offset_y + self.screen_view.height).contains(&screen_y)
+    }
+}
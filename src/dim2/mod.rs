@@ -1,4 +1,6 @@
 pub mod grid;
+pub mod hex;
 pub mod collider;
 pub mod vec;
 pub mod movement;
+pub mod noise;
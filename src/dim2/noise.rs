@@ -0,0 +1,222 @@
+use crate::random::Rng;
+
+use super::grid::{Grid, RectSize};
+
+/// algorithm used to calculate the noise between the integer lattice points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NoiseKind {
+    /// smoothly interpolated random values, blocky compared to the gradient based kinds
+    Value,
+    /// interpolated random gradients on a square lattice
+    Perlin,
+    /// random gradients on a triangular lattice, fewer directional artifacts and cheaper than `Perlin`
+    Simplex,
+}
+
+/// deterministic, continuous 2D noise returning values in about `[-1, 1]`.
+///
+/// the noise repeats every 256 units in both directions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Noise {
+    kind: NoiseKind,
+    /// shuffled values 0 to 255, repeated once to avoid wrapping the index
+    permutation: [u8; 512],
+}
+
+const GRADIENTS: [(f32, f32); 8] = [
+    (1., 0.),
+    (-1., 0.),
+    (0., 1.),
+    (0., -1.),
+    (std::f32::consts::FRAC_1_SQRT_2, std::f32::consts::FRAC_1_SQRT_2),
+    (-std::f32::consts::FRAC_1_SQRT_2, std::f32::consts::FRAC_1_SQRT_2),
+    (std::f32::consts::FRAC_1_SQRT_2, -std::f32::consts::FRAC_1_SQRT_2),
+    (-std::f32::consts::FRAC_1_SQRT_2, -std::f32::consts::FRAC_1_SQRT_2),
+];
+
+impl Noise {
+    pub fn new(kind: NoiseKind, seed: u64) -> Self {
+        let mut rng = Rng::new(seed);
+        let mut values: [u8; 256] = std::array::from_fn(|i| i as u8);
+        rng.shuffle(&mut values);
+
+        Noise {
+            kind,
+            permutation: std::array::from_fn(|i| values[i % 256]),
+        }
+    }
+
+    pub fn kind(&self) -> NoiseKind {
+        self.kind
+    }
+
+    pub fn sample(&self, x: f32, y: f32) -> f32 {
+        match self.kind {
+            NoiseKind::Value => self.value(x, y),
+            NoiseKind::Perlin => self.perlin(x, y),
+            NoiseKind::Simplex => self.simplex(x, y),
+        }
+    }
+
+    fn hash(&self, x: i32, y: i32) -> usize {
+        self.permutation[self.permutation[(x & 255) as usize] as usize + (y & 255) as usize] as usize
+    }
+
+    fn gradient_dot(&self, x: i32, y: i32, dx: f32, dy: f32) -> f32 {
+        let (gx, gy) = GRADIENTS[self.hash(x, y) % GRADIENTS.len()];
+        gx * dx + gy * dy
+    }
+
+    fn value(&self, x: f32, y: f32) -> f32 {
+        let (x0, y0) = (x.floor(), y.floor());
+        let (ix, iy) = (x0 as i32, y0 as i32);
+        let (u, v) = (fade(x - x0), fade(y - y0));
+        let corner = |cx: i32, cy: i32| self.hash(cx, cy) as f32 / 127.5 - 1.;
+
+        lerp(lerp(corner(ix, iy), corner(ix + 1, iy), u), lerp(corner(ix, iy + 1), corner(ix + 1, iy + 1), u), v)
+    }
+
+    fn perlin(&self, x: f32, y: f32) -> f32 {
+        let (x0, y0) = (x.floor(), y.floor());
+        let (ix, iy) = (x0 as i32, y0 as i32);
+        let (dx, dy) = (x - x0, y - y0);
+        let (u, v) = (fade(dx), fade(dy));
+
+        let top = lerp(self.gradient_dot(ix, iy, dx, dy), self.gradient_dot(ix + 1, iy, dx - 1., dy), u);
+        let bottom = lerp(self.gradient_dot(ix, iy + 1, dx, dy - 1.), self.gradient_dot(ix + 1, iy + 1, dx - 1., dy - 1.), u);
+        // the largest possible value of 2D perlin noise is sqrt(0.5)
+        lerp(top, bottom, v) * std::f32::consts::SQRT_2
+    }
+
+    fn simplex(&self, x: f32, y: f32) -> f32 {
+        // skewing factors between the square and the triangular lattice
+        const SKEW: f32 = 0.366_025_4; // (sqrt(3) - 1) / 2
+        const UNSKEW: f32 = 0.211_324_87; // (3 - sqrt(3)) / 6
+
+        let skew = (x + y) * SKEW;
+        let (i, j) = ((x + skew).floor(), (y + skew).floor());
+        let unskew = (i + j) * UNSKEW;
+        let (x0, y0) = (x - (i - unskew), y - (j - unskew));
+
+        // the triangle containing the point is either the upper or lower half of the skewed square
+        let (step_i, step_j) = if x0 > y0 { (1, 0) } else { (0, 1) };
+        let corners = [
+            (0, 0, x0, y0),
+            (step_i, step_j, x0 - step_i as f32 + UNSKEW, y0 - step_j as f32 + UNSKEW),
+            (1, 1, x0 - 1. + 2. * UNSKEW, y0 - 1. + 2. * UNSKEW),
+        ];
+
+        let (i, j) = (i as i32, j as i32);
+        let sum: f32 = corners
+            .iter()
+            .map(|&(ci, cj, dx, dy)| {
+                let falloff = 0.5 - dx * dx - dy * dy;
+                if falloff <= 0. {
+                    0.
+                } else {
+                    falloff.powi(4) * self.gradient_dot(i + ci, j + cj, dx, dy)
+                }
+            })
+            .sum();
+        // scales the result to about [-1, 1]
+        sum * 70.
+    }
+}
+
+/// sums several layers (octaves) of noise with rising frequency and falling amplitude (fractal Brownian motion)
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoiseField {
+    pub noise: Noise,
+    /// scale of the first octave, e.g. 0.1 gives features about 10 units wide
+    pub frequency: f32,
+    pub octaves: usize,
+    /// factor the frequency changes by from one octave to the next
+    pub lacunarity: f32,
+    /// factor the amplitude changes by from one octave to the next
+    pub persistence: f32,
+}
+
+impl NoiseField {
+    pub fn new(kind: NoiseKind, seed: u64) -> Self {
+        NoiseField {
+            noise: Noise::new(kind, seed),
+            frequency: 0.05,
+            octaves: 4,
+            lacunarity: 2.,
+            persistence: 0.5,
+        }
+    }
+
+    /// noise at the given coordinates, normalized to about `[-1, 1]` regardless of the amount of octaves
+    pub fn sample(&self, x: f32, y: f32) -> f32 {
+        let mut frequency = self.frequency;
+        let mut amplitude = 1.;
+        let mut total = 0.;
+        let mut max_amplitude = 0.;
+
+        for octave in 0..self.octaves {
+            // shifts every octave so the lattice points of different octaves do not line up
+            let offset = octave as f32 * 17.31;
+            total += self.noise.sample(x * frequency + offset, y * frequency + offset) * amplitude;
+            max_amplitude += amplitude;
+            frequency *= self.lacunarity;
+            amplitude *= self.persistence;
+        }
+
+        if max_amplitude > 0. {
+            total / max_amplitude
+        } else {
+            0.
+        }
+    }
+
+    /// samples the field at every grid position
+    pub fn to_grid(&self, size: RectSize) -> Grid<f32> {
+        let values: Vec<_> = size.iter().map(|pos| self.sample(pos.x as f32, pos.y as f32)).collect();
+        Grid::new(size.width, size.height, values.into_boxed_slice())
+    }
+}
+
+/// linearly maps `value` from the range `from` to the range `to`, values outside of `from` end up outside of `to`
+pub fn remap(value: f32, from: (f32, f32), to: (f32, f32)) -> f32 {
+    to.0 + (value - from.0) / (from.1 - from.0) * (to.1 - to.0)
+}
+
+/// picks the value of the first band whose upper bound is above `value`, or the value of the last band.
+///
+/// bands have to be sorted by their upper bound, panics if there are none.
+pub fn threshold<T: Copy>(value: f32, bands: &[(f32, T)]) -> T {
+    bands.iter().find(|(upper_bound, _)| value < *upper_bound).unwrap_or_else(|| bands.last().expect("no threshold bands")).1
+}
+
+impl Grid<f32> {
+    /// smallest and largest value, `None` for an empty grid
+    pub fn value_range(&self) -> Option<(f32, f32)> {
+        self.iter().fold(None, |range, &value| match range {
+            None => Some((value, value)),
+            Some((min, max)) => Some((value.min(min), value.max(max))),
+        })
+    }
+
+    /// stretches the values so they cover exactly the range `to`
+    pub fn normalize(&mut self, to: (f32, f32)) {
+        if let Some(from) = self.value_range() {
+            for value in self.iter_mut() {
+                *value = if from.0 == from.1 { to.0 } else { remap(*value, from, to) };
+            }
+        }
+    }
+
+    /// turns every value into the value of its band, see `threshold`
+    pub fn threshold<T: Copy>(&self, bands: &[(f32, T)]) -> Grid<T> {
+        self.map(|value| threshold(value, bands))
+    }
+}
+
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6. - 15.) + 10.)
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
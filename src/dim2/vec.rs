@@ -16,6 +16,12 @@ pub fn rotate_90_degrees_right(x: f32, y: f32) -> (f32, f32) {
     (y, -x)
 }
 
+/// rotates counter clockwise by `angle` radians
+pub fn rotate(x: f32, y: f32, angle: f32) -> (f32, f32) {
+    let (sin, cos) = angle.sin_cos();
+    (x * cos - y * sin, x * sin + y * cos)
+}
+
 #[test]
 fn test_rotation_left() {
     // up
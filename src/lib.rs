@@ -11,3 +11,6 @@ pub use todo_list::TodoList;
 
 pub mod screen_view;
 pub use screen_view::ScreenView;
+
+pub mod random;
+pub use random::Rng;
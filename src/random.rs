@@ -0,0 +1,194 @@
+use std::ops::Range;
+
+use crate::dim2::{
+    collider::Collider,
+    grid::{Position, RectSize},
+};
+
+/// small, deterministic pseudo random number generator (xoshiro256**).
+///
+/// the same seed always produces the same sequence of values on every platform. the state can be saved with
+/// `to_bytes` and restored with `from_bytes` to continue the exact same sequence, e.g. for replays.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rng {
+    state: [u64; 4],
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // splitmix64 spreads the seed over the whole state, which must never be all zeros
+        let mut seed = seed;
+        let mut next_seed = || {
+            seed = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = seed;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        };
+        Rng {
+            state: [next_seed(), next_seed(), next_seed(), next_seed()],
+        }
+    }
+
+    /// continues the sequence of the rng the state was taken from, `None` for the invalid all zero state
+    pub fn from_state(state: [u64; 4]) -> Option<Self> {
+        if state == [0; 4] {
+            None
+        } else {
+            Some(Rng { state })
+        }
+    }
+
+    pub fn state(&self) -> [u64; 4] {
+        self.state
+    }
+
+    /// little endian bytes of the state
+    pub fn to_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        for (chunk, value) in bytes.chunks_exact_mut(8).zip(self.state) {
+            chunk.copy_from_slice(&value.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// restores an rng saved with `to_bytes`, `None` for the invalid all zero state
+    pub fn from_bytes(bytes: [u8; 32]) -> Option<Self> {
+        Self::from_state(std::array::from_fn(|i| u64::from_le_bytes(bytes[i * 8..(i + 1) * 8].try_into().unwrap())))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let shifted = self.state[1] << 17;
+
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+        self.state[2] ^= shifted;
+        self.state[3] = self.state[3].rotate_left(45);
+
+        result
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// uniformly distributed in `[0, 1)`
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1. / (1u64 << 53) as f64)
+    }
+
+    /// uniformly distributed in `[0, 1)`
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 * (1. / (1u32 << 24) as f32)
+    }
+
+    /// `true` with the given probability
+    pub fn chance(&mut self, probability: f64) -> bool {
+        self.next_f64() < probability
+    }
+
+    /// uniformly distributed value below `bound`, panics if `bound` is zero
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "bound must not be zero");
+        // rejects the values of the incomplete last cycle to avoid a bias towards small values
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return value % bound;
+            }
+        }
+    }
+
+    /// uniformly distributed value in `range`, panics if the range is empty
+    pub fn range_i64(&mut self, range: Range<i64>) -> i64 {
+        assert!(range.start < range.end, "range must not be empty");
+        range.start.wrapping_add(self.below(range.end.wrapping_sub(range.start) as u64) as i64)
+    }
+
+    /// uniformly distributed value in `range`, panics if the range is empty
+    pub fn range_usize(&mut self, range: Range<usize>) -> usize {
+        assert!(range.start < range.end, "range must not be empty");
+        range.start + self.below((range.end - range.start) as u64) as usize
+    }
+
+    /// uniformly distributed value in `range`, panics if the range is empty
+    pub fn range_f32(&mut self, range: Range<f32>) -> f32 {
+        assert!(range.start < range.end, "range must not be empty");
+        // rounding can end up exactly on the excluded end for very small ranges
+        (range.start + self.next_f32() * (range.end - range.start)).min(range.end.next_down())
+    }
+
+    /// puts the values into a random order, every order is equally likely
+    pub fn shuffle<T>(&mut self, values: &mut [T]) {
+        for i in (1..values.len()).rev() {
+            values.swap(i, self.range_usize(0..i + 1));
+        }
+    }
+
+    /// uniformly chosen value, `None` if there are no values
+    pub fn choose<'a, T>(&mut self, values: &'a [T]) -> Option<&'a T> {
+        if values.is_empty() {
+            None
+        } else {
+            Some(&values[self.range_usize(0..values.len())])
+        }
+    }
+
+    /// index chosen with a probability proportional to its weight, `None` if no weight is positive.
+    ///
+    /// negative and `NaN` weights count as zero.
+    pub fn weighted_index(&mut self, weights: &[f64]) -> Option<usize> {
+        let weight_at = |i: usize| if weights[i] > 0. { weights[i] } else { 0. };
+        let total: f64 = (0..weights.len()).map(weight_at).sum();
+        if total <= 0. {
+            return None;
+        }
+
+        let mut remaining = self.next_f64() * total;
+        let mut last_positive = None;
+        for i in (0..weights.len()).filter(|&i| weight_at(i) > 0.) {
+            if remaining < weight_at(i) {
+                return Some(i);
+            }
+            remaining -= weight_at(i);
+            last_positive = Some(i);
+        }
+        // floating point errors can leave a tiny rest after the last weight
+        last_positive
+    }
+
+    /// value chosen with a probability proportional to its weight, see `weighted_index`
+    pub fn choose_weighted<'a, T>(&mut self, values: &'a [T], weight: impl Fn(&T) -> f64) -> Option<&'a T> {
+        let weights: Vec<_> = values.iter().map(weight).collect();
+        self.weighted_index(&weights).map(|i| &values[i])
+    }
+
+    /// uniformly chosen position inside a grid of the given size, panics if the size is empty
+    pub fn position_in(&mut self, size: RectSize) -> Position {
+        assert!(!size.is_empty(), "size must not be empty");
+        Position::new(self.range_usize(0..size.width) as i64, self.range_usize(0..size.height) as i64)
+    }
+
+    /// uniformly distributed point inside of `collider` placed at the given position
+    pub fn point_in_collider(&mut self, collider: &Collider, position_x: f32, position_y: f32) -> (f32, f32) {
+        match collider {
+            Collider::Circle(radius) => {
+                // the square root spreads the points evenly over the area instead of clustering them at the center
+                let distance = radius * self.next_f32().sqrt();
+                let angle = self.next_f32() * std::f32::consts::TAU;
+                (position_x + distance * angle.cos(), position_y + distance * angle.sin())
+            }
+            Collider::Rectangle(rel_rect) => {
+                let rect = rel_rect.to_absolute(position_x, position_y);
+                (
+                    rect.left + self.next_f32() * (rect.right - rect.left),
+                    rect.top + self.next_f32() * (rect.bottom - rect.top),
+                )
+            }
+        }
+    }
+}
@@ -1,7 +1,42 @@
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub struct ScreenView {
     pub offset_x: f32,
     pub offset_y: f32,
     pub width: f32,
     pub height: f32,
 }
+
+impl ScreenView {
+    /// rectangle spanned by two arbitrary corners, e.g. the start and end of a mouse drag
+    pub fn from_corners(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        ScreenView {
+            offset_x: x1.min(x2),
+            offset_y: y1.min(y2),
+            width: (x1 - x2).abs(),
+            height: (y1 - y2).abs(),
+        }
+    }
+
+    pub fn contains_point(&self, x: f32, y: f32) -> bool {
+        (self.offset_x..self.offset_x + self.width).contains(&x) && (self.offset_y..self.offset_y + self.height).contains(&y)
+    }
+
+    /// overlapping area of both views, `None` if they do not overlap or only touch
+    pub fn intersection(&self, other: &ScreenView) -> Option<ScreenView> {
+        let left = self.offset_x.max(other.offset_x);
+        let top = self.offset_y.max(other.offset_y);
+        let right = (self.offset_x + self.width).min(other.offset_x + other.width);
+        let bottom = (self.offset_y + self.height).min(other.offset_y + other.height);
+
+        if left >= right || top >= bottom {
+            return None;
+        }
+
+        Some(ScreenView {
+            offset_x: left,
+            offset_y: top,
+            width: right - left,
+            height: bottom - top,
+        })
+    }
+}
@@ -0,0 +1,92 @@
+use test_case::test_case;
+use xs_games_rs::dim2::grid::{
+    automaton::LifeRuleParseError,
+    patterns::{adjacent_pattern, surrounding_pattern},
+    CellularAutomaton, Grid, LifeRule, Position, WrapMode,
+};
+
+fn create_life_grid(width: usize, height: usize, alive: &[(i64, i64)]) -> Grid<bool> {
+    let mut grid = Grid::new(width, height, vec![false; width * height].into_boxed_slice());
+    for &(x, y) in alive {
+        grid[Position::new(x, y)] = true;
+    }
+    grid
+}
+
+fn living_cells(grid: &Grid<bool>) -> Vec<Position> {
+    grid.iter_with_position().filter(|(_, &alive)| alive).map(|(pos, _)| pos).collect()
+}
+
+#[test]
+fn blinker_oscillates() {
+    let grid = create_life_grid(5, 5, &[(1, 2), (2, 2), (3, 2)]);
+    let mut automaton = CellularAutomaton::new(grid, &*surrounding_pattern(1), WrapMode::None);
+
+    let changed = automaton.step_life(&LifeRule::CONWAY);
+    assert_eq!(changed, vec![Position::new(2, 1), Position::new(1, 2), Position::new(3, 2), Position::new(2, 3)]);
+    assert_eq!(living_cells(automaton.grid()), vec![Position::new(2, 1), Position::new(2, 2), Position::new(2, 3)]);
+
+    automaton.step_life(&LifeRule::CONWAY);
+    assert_eq!(living_cells(automaton.grid()), vec![Position::new(1, 2), Position::new(2, 2), Position::new(3, 2)]);
+    assert_eq!(automaton.generation(), 2);
+}
+
+#[test]
+fn glider_wraps_around_torus() {
+    let glider = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+    let grid = create_life_grid(6, 6, &glider);
+    let mut automaton = CellularAutomaton::new(grid.clone(), &*surrounding_pattern(1), WrapMode::Both);
+
+    // a glider moves one cell diagonally every 4 generations
+    for _ in 0..24 {
+        automaton.step_life(&LifeRule::CONWAY);
+    }
+    assert_eq!(living_cells(automaton.grid()), living_cells(&grid));
+}
+
+#[test]
+fn block_is_stable() {
+    let grid = create_life_grid(4, 4, &[(1, 1), (2, 1), (1, 2), (2, 2)]);
+    let mut automaton = CellularAutomaton::new(grid, &*surrounding_pattern(1), WrapMode::None);
+    assert_eq!(automaton.run_until_stable(10, |alive, neighbors| LifeRule::CONWAY.apply(alive, neighbors)), 1);
+    assert_eq!(living_cells(automaton.grid()).len(), 4);
+}
+
+#[test]
+fn custom_rule_fire_spread() {
+    // 0 = empty, 1 = tree, 2 = fire, 3 = ash
+    let grid = Grid::new(4, 1, Box::new([2, 1, 1, 0]));
+    let mut automaton = CellularAutomaton::new(grid, &*adjacent_pattern(), WrapMode::None);
+    let spread = |cell: i32, neighbors: &[i32]| match cell {
+        1 if neighbors.contains(&2) => 2,
+        2 => 3,
+        other => other,
+    };
+
+    assert_eq!(automaton.step(spread), vec![Position::new(0, 0), Position::new(1, 0)]);
+    assert_eq!(automaton.step(spread), vec![Position::new(1, 0), Position::new(2, 0)]);
+    assert_eq!(automaton.run_until_stable(10, spread), 2);
+    assert_eq!(automaton.into_grid().into_array().to_vec(), vec![3, 3, 3, 0]);
+}
+
+#[test_case("B3/S23", LifeRule::CONWAY)]
+#[test_case("b5678/s45678", LifeRule::CAVES)]
+#[test_case("B36/S23", LifeRule::new(&[3, 6], &[2, 3]))]
+#[test_case("B/S", LifeRule::new(&[], &[]))]
+fn parse_rule(rule: &str, expected: LifeRule) {
+    assert_eq!(rule.parse::<LifeRule>(), Ok(expected));
+}
+
+#[test_case("B3S23", LifeRuleParseError::InvalidFormat)]
+#[test_case("23/3", LifeRuleParseError::InvalidFormat)]
+#[test_case("B39/S23", LifeRuleParseError::InvalidCount('9'))]
+#[test_case("B3/Sx", LifeRuleParseError::InvalidCount('x'))]
+fn parse_invalid_rule(rule: &str, expected: LifeRuleParseError) {
+    assert_eq!(rule.parse::<LifeRule>(), Err(expected));
+}
+
+#[test]
+fn rule_display_round_trip() {
+    assert_eq!(LifeRule::CONWAY.to_string(), "B3/S23");
+    assert_eq!(LifeRule::CAVES.to_string().parse::<LifeRule>(), Ok(LifeRule::CAVES));
+}
@@ -0,0 +1,138 @@
+use test_case::test_case;
+use xs_games_rs::dim2::grid::{
+    binary::{read_bytes, LittleEndianCodec, ValueCodec, MAGIC, MAX_VALUES, VERSION},
+    generators::Tile,
+    Grid, GridDecodeError, GridEncoding, RectSize,
+};
+
+struct TileCodec;
+
+impl ValueCodec<Tile> for TileCodec {
+    fn encode(&self, value: Tile, output: &mut Vec<u8>) {
+        output.push(match value {
+            Tile::Wall => b'#',
+            Tile::Floor => b'.',
+        });
+    }
+
+    fn decode(&self, input: &mut &[u8]) -> Result<Tile, GridDecodeError> {
+        match read_bytes(input)? {
+            [b'#'] => Ok(Tile::Wall),
+            [b'.'] => Ok(Tile::Floor),
+            _ => Err(GridDecodeError::InvalidValue),
+        }
+    }
+}
+
+fn create_test_grid() -> Grid<u16> {
+    let values: Vec<u16> = (0..200).map(|i| if i % 50 < 40 { 7 } else { i }).collect();
+    Grid::new(20, 10, values.into_boxed_slice())
+}
+
+#[test_case(GridEncoding::Raw)]
+#[test_case(GridEncoding::RunLength)]
+fn round_trip(encoding: GridEncoding) {
+    let grid = create_test_grid();
+    let bytes = grid.to_bytes(&LittleEndianCodec, encoding);
+    let decoded = Grid::<u16>::from_bytes(&bytes, &LittleEndianCodec).unwrap();
+    assert_eq!(decoded.size(), grid.size());
+    assert_eq!(decoded.into_array(), grid.into_array());
+}
+
+#[test]
+fn header_layout() {
+    let bytes = Grid::new(3, 2, Box::new([true; 6])).to_bytes(&LittleEndianCodec, GridEncoding::RunLength);
+    assert_eq!(&bytes[..4], &MAGIC);
+    assert_eq!(&bytes[4..6], &VERSION.to_le_bytes());
+    assert_eq!(bytes[6], GridEncoding::RunLength as u8);
+    assert_eq!(&bytes[7..15], &[3, 0, 0, 0, 2, 0, 0, 0]);
+    // one run of 6 `true` values
+    assert_eq!(&bytes[15..], &[6, 1]);
+}
+
+#[test]
+fn run_length_is_compact() {
+    let grid = Grid::new(100, 100, vec![Tile::Wall; 10_000].into_boxed_slice());
+    let raw = grid.to_bytes(&TileCodec, GridEncoding::Raw);
+    let compressed = grid.to_bytes(&TileCodec, GridEncoding::RunLength);
+    assert_eq!(raw.len(), 15 + 10_000);
+    // 10000 needs a two byte run length
+    assert_eq!(compressed.len(), 15 + 2 + 1);
+    assert_eq!(Grid::from_bytes(&compressed, &TileCodec).unwrap().to_bytes(&TileCodec, GridEncoding::Raw), raw);
+}
+
+#[test_case(GridEncoding::Raw)]
+#[test_case(GridEncoding::RunLength)]
+fn empty_grid(encoding: GridEncoding) {
+    let grid = Grid::<f32>::new(0, 4, Box::new([]));
+    let decoded = Grid::<f32>::from_bytes(&grid.to_bytes(&LittleEndianCodec, encoding), &LittleEndianCodec).unwrap();
+    assert_eq!(decoded.size(), RectSize::new(0, 4));
+}
+
+#[test_case(GridEncoding::Raw)]
+#[test_case(GridEncoding::RunLength)]
+fn truncated_input(encoding: GridEncoding) {
+    let bytes = create_test_grid().to_bytes(&LittleEndianCodec, encoding);
+    for len in 0..bytes.len() {
+        assert_eq!(Grid::<u16>::from_bytes(&bytes[..len], &LittleEndianCodec).err(), Some(GridDecodeError::UnexpectedEnd));
+    }
+}
+
+fn modified(change: impl FnOnce(&mut Vec<u8>)) -> Result<Grid<bool>, GridDecodeError> {
+    let mut bytes = Grid::new(2, 2, Box::new([true, true, false, true])).to_bytes(&LittleEndianCodec, GridEncoding::RunLength);
+    change(&mut bytes);
+    Grid::from_bytes(&bytes, &LittleEndianCodec)
+}
+
+#[test]
+fn invalid_input() {
+    assert!(modified(|_| {}).is_ok());
+    assert_eq!(modified(|bytes| bytes[0] = b'x').err(), Some(GridDecodeError::InvalidMagic));
+    assert_eq!(modified(|bytes| bytes[4] = 9).err(), Some(GridDecodeError::UnsupportedVersion(9)));
+    assert_eq!(modified(|bytes| bytes[6] = 5).err(), Some(GridDecodeError::UnknownEncoding(5)));
+    assert_eq!(modified(|bytes| bytes.push(0)).err(), Some(GridDecodeError::TrailingBytes));
+    // first run is `2 x true`
+    assert_eq!(modified(|bytes| bytes[15] = 0).err(), Some(GridDecodeError::InvalidRunLength));
+    assert_eq!(modified(|bytes| bytes[15] = 5).err(), Some(GridDecodeError::InvalidRunLength));
+    assert_eq!(modified(|bytes| bytes[16] = 2).err(), Some(GridDecodeError::InvalidValue));
+}
+
+#[test]
+fn oversized_header() {
+    let mut bytes = Grid::new(1, 1, Box::new([0u8])).to_bytes(&LittleEndianCodec, GridEncoding::Raw);
+    bytes[7..15].copy_from_slice(&[0xff; 8]);
+    // the size is checked before any value is read
+    assert_eq!(Grid::<u8>::from_bytes(&bytes, &LittleEndianCodec).err(), Some(GridDecodeError::InvalidSize));
+
+    // 65535 x 65535 values in a single run would need about 4 GB
+    let mut bytes = Grid::new(1, 1, Box::new([0u8])).to_bytes(&LittleEndianCodec, GridEncoding::RunLength);
+    bytes.truncate(7);
+    bytes.extend_from_slice(&[0xff, 0xff, 0, 0, 0xff, 0xff, 0, 0]);
+    bytes.extend_from_slice(&[0x81, 0x80, 0xf8, 0xff, 0x0f, 0]);
+    assert_eq!(Grid::<u8>::from_bytes(&bytes, &LittleEndianCodec).err(), Some(GridDecodeError::InvalidSize));
+}
+
+#[test]
+fn size_limit() {
+    let bytes = Grid::new(4, 4, Box::new([1u8; 16])).to_bytes(&LittleEndianCodec, GridEncoding::RunLength);
+    assert!(Grid::<u8>::from_bytes_with_limit(&bytes, &LittleEndianCodec, 16).is_ok());
+    assert_eq!(Grid::<u8>::from_bytes_with_limit(&bytes, &LittleEndianCodec, 15).err(), Some(GridDecodeError::InvalidSize));
+
+    let side = (MAX_VALUES as f64).sqrt() as u32 + 1;
+    let mut bytes = bytes;
+    bytes[7..15].copy_from_slice(&[side.to_le_bytes(), side.to_le_bytes()].concat());
+    assert_eq!(Grid::<u8>::from_bytes(&bytes, &LittleEndianCodec).err(), Some(GridDecodeError::InvalidSize));
+}
+
+#[test]
+fn overlong_run_length() {
+    // a run length of 1 with a tenth byte whose bits do not fit into a u64 anymore
+    let mut bytes = Grid::new(1, 1, Box::new([true])).to_bytes(&LittleEndianCodec, GridEncoding::RunLength);
+    bytes.truncate(15);
+    bytes.extend_from_slice(&[0x81, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x02, 1]);
+    assert_eq!(Grid::<bool>::from_bytes(&bytes, &LittleEndianCodec).err(), Some(GridDecodeError::InvalidRunLength));
+
+    // padded with zero bits the same run length decodes to 1
+    bytes[24] = 0;
+    assert!(Grid::<bool>::from_bytes(&bytes, &LittleEndianCodec).is_ok());
+}
@@ -0,0 +1,76 @@
+use std::f32::consts::FRAC_PI_2;
+
+use xs_games_rs::{
+    dim2::grid::{Camera, CameraTranslation, GridRect, Position, RectSize, ScreenTranslation},
+    ScreenView,
+};
+
+#[test]
+fn unmoved_camera() {
+    let camera_translation = create_test_camera_translation();
+    assert_eq!(camera_translation.get_grid_position(15., 95.), Some(Position::new(1, 9)));
+    assert_eq!(camera_translation.block_center_to_screen_position(1, 9), (15., 95.));
+    assert_eq!(camera_translation.visible_positions().len(), 100);
+}
+
+#[test]
+fn zoom() {
+    let mut camera_translation = create_test_camera_translation();
+    camera_translation.camera.zoom_by(2.);
+
+    assert_eq!(camera_translation.get_grid_position(50., 50.), Some(Position::new(5, 5)));
+    assert_eq!(camera_translation.get_grid_position(0., 0.), Some(Position::new(2, 2)));
+    assert_eq!(camera_translation.visible_region(), Some(GridRect::new(Position::new(2, 2), RectSize::new(6, 6))));
+}
+
+#[test]
+fn pan() {
+    let mut camera_translation = create_test_camera_translation();
+    camera_translation.camera.zoom_by(2.);
+    camera_translation.camera.pan(20., 0.);
+
+    assert_eq!(camera_translation.get_grid_position(50., 50.), Some(Position::new(6, 5)));
+
+    camera_translation.camera.pan(1000., 0.);
+    assert_eq!(camera_translation.get_grid_position(50., 50.), None);
+    assert!(camera_translation.visible_region().is_none());
+    assert!(camera_translation.visible_positions().is_empty());
+}
+
+#[test]
+fn rotation() {
+    let mut camera_translation = create_test_camera_translation();
+    camera_translation.camera.rotate(FRAC_PI_2);
+
+    let (x, y) = camera_translation.block_center_to_screen_position(7, 2);
+    assert!((x - 75.).abs() < 0.001 && (y - 75.).abs() < 0.001);
+    assert_eq!(camera_translation.get_grid_position(x, y), Some(Position::new(7, 2)));
+}
+
+#[test]
+fn camera_round_trip() {
+    let screen_view = create_test_screen_view();
+    let camera = Camera {
+        center_x: 30.,
+        center_y: 70.,
+        zoom: 3.,
+        rotation: 0.7,
+    };
+
+    let (screen_x, screen_y) = camera.world_to_screen(&screen_view, 12., 34.);
+    let (world_x, world_y) = camera.screen_to_world(&screen_view, screen_x, screen_y);
+    assert!((world_x - 12.).abs() < 0.001 && (world_y - 34.).abs() < 0.001);
+}
+
+fn create_test_screen_view() -> ScreenView {
+    ScreenView {
+        offset_x: 0.,
+        offset_y: 0.,
+        width: 100.,
+        height: 100.,
+    }
+}
+
+fn create_test_camera_translation() -> CameraTranslation {
+    CameraTranslation::centered(ScreenTranslation::new(create_test_screen_view(), RectSize::new(10, 10)))
+}
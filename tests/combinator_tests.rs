@@ -0,0 +1,85 @@
+use test_case::test_case;
+use xs_games_rs::dim2::grid::{
+    patterns::{new_rectangle_pattern, surrounding_pattern, PatternPositions},
+    EdgeHandling, Grid, Position, WrapMode,
+};
+
+fn create_test_grid() -> Grid<i32> {
+    // 1 2 3
+    // 4 5 6
+    Grid::new(3, 2, Box::new([1, 2, 3, 4, 5, 6]))
+}
+
+#[test]
+fn map() {
+    let grid = create_test_grid();
+    assert_eq!(grid.map(|value| value * 10).into_array().to_vec(), vec![10, 20, 30, 40, 50, 60]);
+
+    let positions = grid.map_with_position(|pos, _| pos);
+    assert_eq!(positions[Position::new(2, 1)], Position::new(2, 1));
+    assert_eq!(positions.size(), grid.size());
+}
+
+#[test]
+fn zip_with() {
+    let grid = create_test_grid();
+    let flags = grid.map(|value| value % 2 == 0);
+    let zipped = grid.zip_with(&flags, |value, even| if even { value } else { 0 });
+    assert_eq!(zipped.into_array().to_vec(), vec![0, 2, 0, 4, 0, 6]);
+}
+
+#[test]
+#[should_panic]
+fn zip_with_size_mismatch() {
+    create_test_grid().zip_with(&Grid::new(2, 3, Box::new([0; 6])), |a, b| a + b);
+}
+
+#[test_case(EdgeHandling::Skip, vec![11, 19, 13, 8, 16, 10])]
+#[test_case(EdgeHandling::Clamp, vec![20, 25, 30, 26, 31, 36])]
+#[test_case(EdgeHandling::Wrap(WrapMode::Both), vec![35, 34, 33, 23, 22, 21])]
+#[test_case(EdgeHandling::Wrap(WrapMode::Horizontal), vec![20, 19, 18, 17, 16, 15])]
+#[test_case(EdgeHandling::Wrap(WrapMode::None), vec![11, 19, 13, 8, 16, 10])]
+fn convolve_surrounding_sum(edge_handling: EdgeHandling, expected: Vec<i32>) {
+    let grid = create_test_grid();
+    let sums = grid.convolve(&*surrounding_pattern(1), edge_handling, |_, neighbors| neighbors.iter().sum::<i32>());
+    assert_eq!(sums.into_array().to_vec(), expected);
+}
+
+#[test]
+fn convolve_receives_cell_value() {
+    let grid = create_test_grid();
+    // horizontal difference to the right neighbor
+    let right_neighbor = new_rectangle_pattern(0, 0, 1, 0);
+    let gradient = grid.convolve(&right_neighbor, EdgeHandling::Clamp, |value, neighbors| neighbors[0] - value);
+    assert_eq!(gradient.into_array().to_vec(), vec![1, 1, 0, 1, 1, 0]);
+}
+
+/// the left neighbor for cells in even columns and the right neighbor for cells in odd columns
+struct AlternatingPattern;
+
+impl PatternPositions for AlternatingPattern {
+    fn get_pattern_positions(&self, center: Position) -> Box<[Position]> {
+        let dx = if center.x % 2 == 0 { -1 } else { 1 };
+        Box::new([center + Position::new(dx, 0)])
+    }
+}
+
+#[test]
+fn convolve_asks_pattern_for_every_center() {
+    let grid = create_test_grid();
+    let sums = grid.convolve(&AlternatingPattern, EdgeHandling::Skip, |_, neighbors| neighbors.iter().sum::<i32>());
+    for (pos, &sum) in sums.iter_with_position() {
+        assert_eq!(sum, grid.get_neighborhood(pos, &AlternatingPattern, EdgeHandling::Skip).iter().sum::<i32>());
+    }
+    assert_eq!(sums.into_array().to_vec(), vec![0, 3, 2, 0, 6, 5]);
+}
+
+#[test]
+fn neighborhood_edges() {
+    let grid = create_test_grid();
+    let pattern = surrounding_pattern(1);
+    assert_eq!(grid.get_neighborhood(Position::new(0, 0), &*pattern, EdgeHandling::Skip).len(), 3);
+    assert_eq!(grid.get_neighborhood(Position::new(0, 0), &*pattern, EdgeHandling::Clamp).len(), 8);
+    assert_eq!(grid.get_neighborhood(Position::new(0, 0), &*pattern, EdgeHandling::Wrap(WrapMode::Both)).len(), 8);
+    assert_eq!(grid.get_neighborhood(Position::new(0, 0), &*pattern, EdgeHandling::Wrap(WrapMode::Vertical)).len(), 5);
+}
@@ -0,0 +1,63 @@
+use xs_games_rs::dim2::grid::{
+    pathing::get_shortest_path,
+    patterns::{adjacent_pattern, get_grid_values_from_pattern, PatternPositions},
+    Direction, Grid, Position, RectSize,
+};
+
+#[test]
+fn compact_positions() {
+    assert_eq!(std::mem::size_of::<Position<i16>>(), 4);
+    assert_eq!(std::mem::size_of::<Position<i32>>(), 8);
+    assert_eq!(std::mem::size_of::<Position>(), 16);
+}
+
+#[test]
+fn grid_access() {
+    let mut grid = Grid::new(3, 2, Box::new([0; 6]));
+    *grid.get_mut_as(Position::<i16>::new(2, 1)).unwrap() = 5;
+    grid.set_as(Position::<i32>::new(0, 1), 3).unwrap();
+
+    assert_eq!(grid.get(Position::new(2, 1)), Some(5));
+    assert_eq!(grid.get_as(Position::<i16>::new(0, 1)), Some(3));
+    assert_eq!(grid.get_as(Position::<i16>::new(-1, 1)), None);
+}
+
+#[test]
+fn untyped_positions() {
+    let mut grid = Grid::new(2, 2, Box::new([1, 2, 3, 4]));
+    grid[Position::ZERO] = 5;
+    assert_eq!(grid.get(Position::ZERO), Some(5));
+    assert_eq!(grid[Position::new(1, 1)], 4);
+    assert_eq!(Position::ZERO.neighbor(Direction::Right), Position::new(1, 0));
+}
+
+#[test]
+fn iteration() {
+    let grid = Grid::new(2, 2, Box::new([1, 2, 3, 4]));
+    let positions: Vec<Position<i16>> = grid.iter_with_position_as::<i16>().map(|(pos, _)| pos).collect();
+    let expected: Vec<Position<i16>> = RectSize::new(2, 2).iter_as::<i16>().collect();
+    assert_eq!(positions, expected);
+    assert_eq!(expected.last(), Some(&Position::new(1, 1)));
+}
+
+#[test]
+fn pattern_and_pathing() {
+    let grid = Grid::new(3, 3, Box::new([true; 9]));
+    let center = Position::<i16>::new(1, 1);
+
+    let neighbors = adjacent_pattern().get_pattern_positions(center);
+    assert!(neighbors.contains(&Position::new(1, 0)));
+    assert_eq!(get_grid_values_from_pattern(&grid, center, &*adjacent_pattern()).len(), neighbors.len());
+
+    let (path, cost) = get_shortest_path(&grid, Position::<i16>::new(0, 0), Position::new(2, 2), &|tile| tile).unwrap();
+    assert_eq!(cost, 4);
+    assert_eq!(path.last(), Some(&Position::<i16>::new(2, 2)));
+}
+
+#[test]
+fn casting() {
+    let position = Position::<i64>::new(40_000, -3);
+    assert_eq!(position.try_cast::<i32>(), Some(Position::new(40_000, -3)));
+    assert_eq!(position.try_cast::<i16>(), None);
+    assert_eq!(Position::<i16>::new(7, -3).try_cast::<i64>(), Some(Position::new(7, -3)));
+}
@@ -0,0 +1,53 @@
+use test_case::test_case;
+use xs_games_rs::dim2::grid::{Direction, Position};
+
+#[test]
+fn opposite_cancels_out() {
+    for direction in Direction::ALL {
+        assert_eq!(direction.offset() + direction.opposite().offset(), Position::ZERO);
+        assert_eq!(direction.opposite().opposite(), direction);
+    }
+}
+
+#[test]
+fn rotation() {
+    assert_eq!(Direction::Up.rotate_clockwise(), Direction::UpRight);
+    assert_eq!(Direction::Up.rotate_counter_clockwise(), Direction::UpLeft);
+    assert_eq!(Direction::Left.rotate_clockwise_90(), Direction::Up);
+    assert_eq!(Direction::Left.rotate_counter_clockwise_90(), Direction::Down);
+    for direction in Direction::ALL {
+        assert_eq!(direction.rotate_clockwise().rotate_counter_clockwise(), direction);
+        assert_eq!(direction.rotate_clockwise_steps(8), direction);
+    }
+    // usize::MAX is 7 more than a multiple of 8
+    assert_eq!(Direction::Up.rotate_clockwise_steps(usize::MAX), Direction::UpLeft);
+}
+
+#[test_case(Position::new(0, -1), Some(Direction::Up))]
+#[test_case(Position::new(3, 3), Some(Direction::DownRight))]
+#[test_case(Position::new(-5, 0), Some(Direction::Left))]
+#[test_case(Position::new(-2, -2), Some(Direction::UpLeft))]
+#[test_case(Position::new(5, 1), None)]
+#[test_case(Position::new(-2, -7), None)]
+#[test_case(Position::ZERO, None)]
+fn from_delta(delta: Position, expected: Option<Direction>) {
+    assert_eq!(Direction::from_delta(delta), expected);
+}
+
+#[test]
+fn neighbors() {
+    let position = Position::new(3, 4);
+    assert_eq!(position.neighbor(Direction::Up), Position::new(3, 3));
+    assert_eq!(
+        position.neighbors4(),
+        [Position::new(3, 3), Position::new(4, 4), Position::new(3, 5), Position::new(2, 4)]
+    );
+
+    let neighbors8 = position.neighbors8();
+    assert_eq!(neighbors8.len(), 8);
+    assert!(Direction::DIAGONAL.iter().all(|direction| neighbors8.contains(&position.neighbor(*direction))));
+    for neighbor in neighbors8 {
+        let direction = Direction::from_delta(neighbor - position).unwrap();
+        assert_eq!(position.neighbor(direction), neighbor);
+    }
+}
@@ -0,0 +1,48 @@
+use test_case::test_case;
+use xs_games_rs::dim2::grid::{
+    pathing::{get_shortest_path, get_shortest_path_with_heuristic},
+    patterns::{new_radius_pattern, radius_pattern, PatternPositions},
+    position::DistanceMetric,
+    Grid, Position,
+};
+
+#[test]
+fn integer_distances() {
+    let a = Position::new(1, 2);
+    let b = Position::new(4, -2);
+    assert_eq!(a.manhattan_distance(b), 7);
+    assert_eq!(a.chebyshev_distance(b), 4);
+    assert_eq!(a.distance_squared(b), 25);
+    assert_eq!(a.distance(b), 5.);
+    assert!((a.octile_distance(b) - (1. + 3. * std::f64::consts::SQRT_2)).abs() < 1e-9);
+}
+
+#[test_case(DistanceMetric::Manhattan, 24)]
+#[test_case(DistanceMetric::Chebyshev, 48)]
+#[test_case(DistanceMetric::Euclidean, 28)]
+#[test_case(DistanceMetric::Octile, 28)]
+fn radius_pattern_sizes(metric: DistanceMetric, expected: usize) {
+    assert_eq!(new_radius_pattern(3, metric).get_pattern_positions(Position::ZERO).len(), expected);
+}
+
+#[test]
+fn radius_pattern_cached() {
+    let positions = radius_pattern(3, DistanceMetric::Euclidean).get_pattern_positions(Position::new(10, 10));
+    assert!(positions.iter().all(|pos| pos.distance(Position::new(10, 10)) <= 3.));
+    assert!(!positions.contains(&Position::new(10, 10)));
+}
+
+#[test_case(DistanceMetric::Manhattan)]
+#[test_case(DistanceMetric::Chebyshev)]
+#[test_case(DistanceMetric::Euclidean)]
+#[test_case(DistanceMetric::Octile)]
+fn admissible_heuristics(heuristic: DistanceMetric) {
+    let mut grid = Grid::new(6, 6, Box::new([true; 36]));
+    for y in 0..5 {
+        grid.set(Position::new(2, y), false).unwrap();
+    }
+
+    let (_, expected_cost) = get_shortest_path(&grid, Position::ZERO, Position::new(5, 0), &|tile| tile).unwrap();
+    let (_, cost) = get_shortest_path_with_heuristic(&grid, Position::ZERO, Position::new(5, 0), heuristic, &|tile| tile).unwrap();
+    assert_eq!(cost, expected_cost);
+}
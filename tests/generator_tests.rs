@@ -0,0 +1,110 @@
+use test_case::test_case;
+use xs_games_rs::{
+    dim2::grid::{
+        generators::{
+            carve_corridor, connect_regions, filled_with_walls, floor_regions, is_connected, BspDungeon, CaveDungeon, DrunkardWalk,
+            RoomsAndCorridors, Tile,
+        },
+        pathing::path_exists,
+        Grid, Position, RectSize,
+    },
+    Rng,
+};
+
+#[derive(Debug, Clone, Copy)]
+enum Generator {
+    Bsp,
+    Rooms,
+    DrunkardWalk,
+    Caves,
+}
+
+fn generate(generator: Generator, seed: u64) -> Grid<Tile> {
+    let size = RectSize::new(48, 32);
+    let mut rng = Rng::new(seed);
+    match generator {
+        Generator::Bsp => BspDungeon::new(size).generate(&mut rng),
+        Generator::Rooms => RoomsAndCorridors::new(size).generate(&mut rng),
+        Generator::DrunkardWalk => DrunkardWalk::new(size).generate(&mut rng),
+        Generator::Caves => CaveDungeon::new(size).generate(&mut rng),
+    }
+}
+
+fn floor_tiles(grid: &Grid<Tile>) -> Vec<Position> {
+    grid.iter_with_position().filter(|(_, tile)| tile.is_floor()).map(|(pos, _)| pos).collect()
+}
+
+#[test_case(Generator::Bsp)]
+#[test_case(Generator::Rooms)]
+#[test_case(Generator::DrunkardWalk)]
+#[test_case(Generator::Caves)]
+fn reproducible_from_seed(generator: Generator) {
+    let first = generate(generator, 7);
+    assert_eq!(first.iter().collect::<Vec<_>>(), generate(generator, 7).iter().collect::<Vec<_>>());
+    assert_ne!(first.iter().collect::<Vec<_>>(), generate(generator, 8).iter().collect::<Vec<_>>());
+}
+
+#[test_case(Generator::Bsp)]
+#[test_case(Generator::Rooms)]
+#[test_case(Generator::DrunkardWalk)]
+#[test_case(Generator::Caves)]
+fn connected_inside_walls(generator: Generator) {
+    for seed in 0..5 {
+        let grid = generate(generator, seed);
+        let floor = floor_tiles(&grid);
+        assert!(floor.len() > grid.len() / 10);
+        assert!(is_connected(&grid));
+        assert!(floor.iter().all(|pos| pos.x > 0 && pos.y > 0 && pos.x < 47 && pos.y < 31));
+        // spot check with the path finding used during generation
+        assert!(path_exists(&grid, floor[0], floor[floor.len() - 1], &Tile::is_floor));
+    }
+}
+
+#[test]
+fn rooms_do_not_overlap() {
+    let (grid, rooms) = RoomsAndCorridors::new(RectSize::new(60, 40)).generate_with_rooms(&mut Rng::new(3));
+    assert!(rooms.len() > 1);
+    for (i, room) in rooms.iter().enumerate() {
+        assert!(room.iter().all(|pos| grid[pos] == Tile::Floor));
+        assert!(rooms[i + 1..].iter().all(|other| !room.expand(1).intersects(other)));
+    }
+}
+
+#[test]
+fn bsp_room_per_leaf() {
+    let dungeon = BspDungeon::new(RectSize::new(50, 50));
+    let (grid, rooms) = dungeon.generate_with_rooms(&mut Rng::new(11));
+    // the inner 48x48 area can be split at least once in each direction
+    assert!(rooms.len() >= 4);
+    assert!(rooms.iter().all(|room| room.size.width >= dungeon.min_room_size && room.size.height >= dungeon.min_room_size));
+    assert!(rooms.iter().all(|room| room.iter().all(|pos| grid[pos] == Tile::Floor)));
+}
+
+#[test]
+fn drunkard_walk_floor_ratio() {
+    let walk = DrunkardWalk {
+        floor_ratio: 0.5,
+        ..DrunkardWalk::new(RectSize::new(22, 12))
+    };
+    assert_eq!(floor_tiles(&walk.generate(&mut Rng::new(1))).len(), 100);
+}
+
+#[test]
+fn connect_separate_regions() {
+    let mut grid = filled_with_walls(RectSize::new(10, 5));
+    carve_corridor(&mut grid, Position::new(1, 1), Position::new(3, 1), true);
+    carve_corridor(&mut grid, Position::new(6, 3), Position::new(8, 3), true);
+    grid[Position::new(8, 1)] = Tile::Floor;
+
+    assert_eq!(floor_regions(&grid).iter().map(Vec::len).collect::<Vec<_>>(), vec![3, 3, 1]);
+    assert!(!is_connected(&grid));
+
+    connect_regions(&mut grid, &mut Rng::new(0));
+    assert!(is_connected(&grid));
+}
+
+#[test]
+fn too_small_for_rooms() {
+    let grid = RoomsAndCorridors::new(RectSize::new(4, 4)).generate(&mut Rng::new(0));
+    assert!(floor_tiles(&grid).is_empty());
+}
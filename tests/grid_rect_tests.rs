@@ -0,0 +1,72 @@
+use xs_games_rs::dim2::grid::{patterns::PatternPositions, Grid, GridRect, Position, RectSize};
+
+fn rect(x: i64, y: i64, width: usize, height: usize) -> GridRect {
+    GridRect::new(Position::new(x, y), RectSize::new(width, height))
+}
+
+#[test]
+fn bounds_and_contains() {
+    let area = rect(1, 2, 3, 2);
+    assert_eq!((area.left(), area.top(), area.right(), area.bottom()), (1, 2, 4, 4));
+    assert!(area.contains(Position::new(1, 2)));
+    assert!(area.contains(Position::new(3, 3)));
+    assert!(!area.contains(Position::new(4, 3)));
+    assert!(!area.contains(Position::new(1, 1)));
+    assert!(area.contains_rect(&rect(2, 2, 2, 1)));
+    assert!(!area.contains_rect(&rect(2, 2, 3, 1)));
+    assert_eq!(GridRect::from_corners(Position::new(3, 3), Position::new(1, 2)), area);
+}
+
+#[test]
+fn intersection_and_union() {
+    let a = rect(0, 0, 4, 4);
+    let b = rect(2, 3, 4, 4);
+    assert_eq!(a.intersection(&b), Some(rect(2, 3, 2, 1)));
+    assert_eq!(a.union(&b), rect(0, 0, 6, 7));
+    assert!(!a.intersects(&rect(4, 0, 1, 1)));
+    assert_eq!(a.union(&rect(10, 10, 0, 0)), a);
+}
+
+#[test]
+fn expand_and_shrink() {
+    let area = rect(2, 2, 3, 3);
+    assert_eq!(area.expand(1), rect(1, 1, 5, 5));
+    assert_eq!(area.expand(1).shrink(1), area);
+    assert_eq!(area.shrink(1), rect(3, 3, 1, 1));
+    assert!(area.shrink(2).is_empty());
+    assert_eq!(area.center(), Position::new(3, 3));
+}
+
+#[test]
+fn splitting() {
+    let area = rect(1, 1, 5, 4);
+    assert_eq!(area.split_horizontally(2), (rect(1, 1, 2, 4), rect(3, 1, 3, 4)));
+    assert_eq!(area.split_vertically(3), (rect(1, 1, 5, 3), rect(1, 4, 5, 1)));
+    assert_eq!(area.split_vertically(10), (area, rect(1, 5, 5, 0)));
+}
+
+#[test]
+fn iteration() {
+    let positions: Vec<_> = rect(-1, 5, 2, 2).iter().collect();
+    assert_eq!(
+        positions,
+        vec![Position::new(-1, 5), Position::new(0, 5), Position::new(-1, 6), Position::new(0, 6)]
+    );
+}
+
+#[test]
+fn grid_region() {
+    let grid = Grid::new(3, 3, Box::new([0, 1, 2, 3, 4, 5, 6, 7, 8]));
+    assert_eq!(grid.rect(), rect(0, 0, 3, 3));
+    assert_eq!(grid.get_region(rect(1, 1, 2, 2)).unwrap().into_array(), Box::new([4, 5, 7, 8]) as Box<[_]>);
+    assert!(grid.get_region(rect(2, 2, 2, 2)).is_none());
+}
+
+#[test]
+fn as_pattern() {
+    let surrounding = rect(-1, -1, 3, 3);
+    let positions = surrounding.get_pattern_positions(Position::new(5, 5));
+    assert_eq!(positions.len(), 9);
+    assert_eq!(positions[0], Position::new(4, 4));
+    assert_eq!(positions[8], Position::new(6, 6));
+}
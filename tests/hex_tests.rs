@@ -0,0 +1,79 @@
+use xs_games_rs::{
+    dim2::hex::{
+        pathing::get_shortest_path,
+        patterns::{HexPatternPositions, NeighborPattern, RingPattern, SpiralPattern},
+        position::hex_distance,
+        HexGrid, HexOrientation, HexPosition, HexScreenTranslation,
+    },
+    ScreenView,
+};
+
+#[test]
+fn distance() {
+    assert_eq!(hex_distance(HexPosition::ZERO, HexPosition::new(3, -1)), 3);
+    assert_eq!(hex_distance(HexPosition::new(-2, 2), HexPosition::new(2, -2)), 4);
+    for neighbor in HexPosition::ZERO.neighbors() {
+        assert_eq!(HexPosition::ZERO.distance(neighbor), 1);
+    }
+}
+
+#[test]
+fn cube_round_trip() {
+    let position = HexPosition::new(2, -5);
+    let (q, r, s) = position.to_cube();
+    assert_eq!(s, 3);
+    assert_eq!(HexPosition::from_cube(q, r, s), position);
+}
+
+#[test]
+fn ring_and_spiral() {
+    let center = HexPosition::new(1, 1);
+    for radius in 1..4 {
+        let ring = RingPattern { radius }.get_pattern_positions(center);
+        assert_eq!(ring.len(), 6 * radius);
+        assert!(ring.iter().all(|&pos| hex_distance(pos, center) == radius as i64));
+    }
+
+    let spiral = SpiralPattern { radius: 2 }.get_pattern_positions(center);
+    assert_eq!(spiral.len(), 19);
+    assert_eq!(spiral[0], center);
+
+    assert_eq!(NeighborPattern.get_pattern_positions(center).len(), 6);
+}
+
+#[test]
+fn hex_pathing() {
+    let mut grid = HexGrid::new(4, 4, Box::new([true; 16]));
+    let (_, cost) = get_shortest_path(&grid, HexPosition::new(0, 3), HexPosition::new(3, 0), &|tile| tile).unwrap();
+    assert_eq!(cost, 3);
+
+    grid[HexPosition::new(1, 2)] = false;
+    grid[HexPosition::new(2, 1)] = false;
+    let (path, cost) = get_shortest_path(&grid, HexPosition::new(0, 3), HexPosition::new(3, 0), &|tile| tile).unwrap();
+    assert_eq!(cost, 4);
+    assert!(path.iter().all(|&pos| grid.get(pos) == Some(true)));
+}
+
+#[test_case::test_case(HexOrientation::PointyTop; "pointy top")]
+#[test_case::test_case(HexOrientation::FlatTop; "flat top")]
+fn screen_round_trip(orientation: HexOrientation) {
+    let screen_view = ScreenView {
+        offset_x: 10.,
+        offset_y: 20.,
+        width: 500.,
+        height: 500.,
+    };
+    let translation = HexScreenTranslation::new(screen_view, orientation, 20.);
+
+    for q in 0..5 {
+        for r in 0..5 {
+            let position = HexPosition::new(q, r);
+            let (x, y) = translation.hex_center_to_screen_position(position);
+            assert_eq!(translation.get_hex_position(x, y), Some(position));
+            // points close to the center stay inside the same hexagon
+            assert_eq!(translation.get_hex_position(x + 8., y - 8.), Some(position));
+        }
+    }
+
+    assert_eq!(translation.get_hex_position(5., 30.), None);
+}
@@ -0,0 +1,61 @@
+use test_case::test_case;
+use xs_games_rs::{
+    dim2::grid::{IsometricLayout, IsometricTranslation, Position, RectSize},
+    ScreenView,
+};
+
+#[test_case(IsometricLayout::Diamond; "diamond")]
+#[test_case(IsometricLayout::Staggered; "staggered")]
+fn center_round_trip(layout: IsometricLayout) {
+    let translation = create_test_translation(layout);
+    for y in 0..4 {
+        for x in 0..4 {
+            let (screen_x, screen_y) = translation.block_center_to_screen_position(x, y);
+            assert_eq!(translation.get_grid_position(screen_x, screen_y), Some(Position::new(x, y)));
+        }
+    }
+}
+
+#[test]
+fn diamond_tile_size() {
+    let translation = create_test_translation(IsometricLayout::Diamond);
+    assert_eq!(translation.tile_width(), 20.);
+    assert_eq!(translation.tile_height(), 10.);
+    assert_eq!(translation.block_center_to_screen_position(0, 0), (50., 25.));
+}
+
+#[test]
+fn diamond_corner_regions() {
+    let translation = create_test_translation(IsometricLayout::Diamond);
+    // the top left corner of the view is outside of the big diamond
+    assert_eq!(translation.get_grid_position(11., 21.), None);
+    // just left and right of the bottom corner of tile (0, 0)
+    assert_eq!(translation.get_grid_position(48., 30.), Some(Position::new(0, 1)));
+    assert_eq!(translation.get_grid_position(52., 30.), Some(Position::new(1, 0)));
+    assert_eq!(translation.get_grid_position(5., 21.), None);
+}
+
+#[test]
+fn staggered_corner_regions() {
+    let translation = create_test_translation(IsometricLayout::Staggered);
+    let (center_x, center_y) = translation.block_center_to_screen_position(1, 2);
+    let half_width = translation.tile_width() / 2.;
+    let half_height = translation.tile_height() / 2.;
+
+    assert_eq!(translation.get_grid_position(center_x, center_y), Some(Position::new(1, 2)));
+    // corners of the bounding box around tile (1, 2) belong to the odd rows
+    assert_eq!(translation.get_grid_position(center_x - half_width + 1., center_y - half_height + 1.), Some(Position::new(0, 1)));
+    assert_eq!(translation.get_grid_position(center_x + half_width - 1., center_y - half_height + 1.), Some(Position::new(1, 1)));
+    assert_eq!(translation.get_grid_position(center_x - half_width + 1., center_y + half_height - 1.), Some(Position::new(0, 3)));
+    assert_eq!(translation.get_grid_position(center_x + half_width - 1., center_y + half_height - 1.), Some(Position::new(1, 3)));
+}
+
+fn create_test_translation(layout: IsometricLayout) -> IsometricTranslation {
+    let screen_view = ScreenView {
+        offset_x: 10.,
+        offset_y: 20.,
+        width: 80.,
+        height: 40.,
+    };
+    IsometricTranslation::new(screen_view, RectSize::new(4, 4), layout)
+}
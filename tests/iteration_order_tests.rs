@@ -0,0 +1,89 @@
+use std::collections::BTreeSet;
+
+use test_case::test_case;
+use xs_games_rs::dim2::grid::{Grid, IterationOrder, Position, RectSize};
+
+const ALL_ORDERS: [IterationOrder; 7] = [
+    IterationOrder::RowMajor,
+    IterationOrder::ColumnMajor,
+    IterationOrder::Reverse,
+    IterationOrder::SpiralFromCenter,
+    IterationOrder::ZOrder,
+    IterationOrder::Hilbert,
+    IterationOrder::Serpentine,
+];
+
+fn positions(order: IterationOrder, width: usize, height: usize) -> Vec<(i64, i64)> {
+    RectSize::new(width, height).iter_ordered(order).map(|pos| (pos.x, pos.y)).collect()
+}
+
+#[test_case(1, 1)]
+#[test_case(4, 4)]
+#[test_case(5, 3)]
+#[test_case(2, 7)]
+#[test_case(0, 3)]
+fn visits_every_position_once(width: usize, height: usize) {
+    let expected: BTreeSet<_> = RectSize::new(width, height).iter().collect();
+    for order in ALL_ORDERS {
+        let visited: Vec<_> = RectSize::new(width, height).iter_ordered(order).collect();
+        assert_eq!(visited.len(), expected.len(), "{order:?}");
+        assert_eq!(visited.into_iter().collect::<BTreeSet<_>>(), expected, "{order:?}");
+    }
+}
+
+#[test]
+fn row_major_matches_iter() {
+    let size = RectSize::new(3, 2);
+    assert!(size.iter_ordered(IterationOrder::RowMajor).eq(size.iter()));
+}
+
+#[test]
+fn simple_orders() {
+    assert_eq!(positions(IterationOrder::ColumnMajor, 2, 2), vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+    assert_eq!(positions(IterationOrder::Reverse, 2, 2), vec![(1, 1), (0, 1), (1, 0), (0, 0)]);
+    assert_eq!(positions(IterationOrder::Serpentine, 3, 2), vec![(0, 0), (1, 0), (2, 0), (2, 1), (1, 1), (0, 1)]);
+    assert_eq!(positions(IterationOrder::ZOrder, 2, 2), vec![(0, 0), (1, 0), (0, 1), (1, 1)]);
+}
+
+#[test]
+fn spiral_from_center() {
+    assert_eq!(
+        positions(IterationOrder::SpiralFromCenter, 3, 3),
+        vec![(1, 1), (2, 1), (2, 2), (1, 2), (0, 2), (0, 1), (0, 0), (1, 0), (2, 0)]
+    );
+
+    let center = Position::new(3, 2);
+    let spiral: Vec<_> = RectSize::new(7, 5).iter_ordered(IterationOrder::SpiralFromCenter).collect();
+    assert_eq!(spiral[0], center);
+    assert!(spiral.windows(2).all(|pair| pair[0].chebyshev_distance(center) <= pair[1].chebyshev_distance(center)));
+}
+
+#[test]
+fn hilbert_is_continuous() {
+    let curve: Vec<_> = RectSize::new(8, 8).iter_ordered(IterationOrder::Hilbert).collect();
+    assert_eq!(curve[0], Position::ZERO);
+    assert!(curve.windows(2).all(|pair| pair[0].manhattan_distance(pair[1]) == 1));
+}
+
+#[test]
+fn grid_iteration_orders() {
+    let mut grid = Grid::new(3, 2, Box::new([0, 1, 2, 3, 4, 5]));
+    let values: Vec<_> = grid.iter_with_position_ordered(IterationOrder::ColumnMajor).map(|(_, &value)| value).collect();
+    assert_eq!(values, vec![0, 3, 1, 4, 2, 5]);
+
+    for (index, (_, value)) in grid.iter_mut_with_position_ordered(IterationOrder::Serpentine).enumerate() {
+        *value = index;
+    }
+    assert_eq!(grid.into_array().to_vec(), vec![0, 1, 2, 5, 4, 3]);
+}
+
+#[test_case(IterationOrder::ZOrder)]
+#[test_case(IterationOrder::Hilbert)]
+fn curves_on_non_square_sizes(order: IterationOrder) {
+    // the order is the one of the enclosing power of two square without the positions outside of the size
+    let square: Vec<_> = positions(order, 8, 8).into_iter().filter(|&(x, y)| x < 5 && y < 3).collect();
+    assert_eq!(positions(order, 5, 3), square);
+
+    // only the covered part of the enclosing 2^16 square is visited
+    assert_eq!(RectSize::new(1 << 16, 1).iter_ordered(order).len(), 1 << 16);
+}
@@ -1,4 +1,4 @@
-use xs_games_rs::dim2::grid::{to_grid_position, Grid};
+use xs_games_rs::dim2::grid::{grid_iter::SplitIterator, to_grid_index, to_grid_position, Grid, Position, RectSize};
 
 #[test]
 fn iterate_over_end() {
@@ -35,3 +35,102 @@ fn iterate_move() {
         assert_eq!(to_grid_position(index as usize, size), i.0);
     }
 }
+
+#[test]
+fn positions_on_non_square_grid() {
+    let grid = Grid::new(3, 2, Box::new([0; 6]));
+    let positions: Vec<_> = grid.iter_with_position().map(|(pos, _)| pos).collect();
+    assert_eq!(positions, RectSize::new(3, 2).iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn iterate_empty_size() {
+    assert_eq!(RectSize::new(0, 3).iter().count(), 0);
+    assert_eq!(RectSize::new(3, 0).iter().count(), 0);
+}
+
+#[test]
+fn iterate_reversed() {
+    let grid = Grid::new(3, 2, Box::new([0, 1, 2, 3, 4, 5]));
+    let reversed: Vec<_> = grid.iter_with_position().rev().collect();
+    let mut forward: Vec<_> = grid.iter_with_position().collect();
+    forward.reverse();
+    assert_eq!(reversed, forward);
+    assert_eq!(reversed[0], (Position::new(2, 1), &5));
+
+    let mut grid = grid;
+    let (pos, value) = grid.iter_mut_with_position().next_back().unwrap();
+    assert_eq!((pos, *value), (Position::new(2, 1), 5));
+
+    let values: Vec<_> = grid.into_iter_with_position().rev().map(|(_, value)| value).collect();
+    assert_eq!(values, vec![5, 4, 3, 2, 1, 0]);
+}
+
+#[test]
+fn meet_in_the_middle() {
+    let size = RectSize::new(3, 1);
+    let mut iter = size.iter();
+    assert_eq!(iter.next(), Some(Position::new(0, 0)));
+    assert_eq!(iter.next_back(), Some(Position::new(2, 0)));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next_back(), Some(Position::new(1, 0)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn exact_size_and_nth() {
+    let grid = Grid::new(4, 3, Box::new([7; 12]));
+    let mut iter = grid.iter_with_position();
+    assert_eq!(iter.len(), 12);
+    assert_eq!(iter.nth(5).map(|(pos, _)| pos), Some(Position::new(1, 1)));
+    assert_eq!(iter.size_hint(), (6, Some(6)));
+    assert!(iter.nth(6).is_none());
+    assert!(iter.next().is_none());
+
+    let size = RectSize::new(4, 3);
+    let mut size_iter = size.iter();
+    assert_eq!(size_iter.nth(11), Some(Position::new(3, 2)));
+    assert_eq!(size_iter.len(), 0);
+
+    let mut into_iter = grid.into_iter_with_position();
+    assert_eq!(into_iter.nth(4).map(|(pos, _)| pos), Some(Position::new(0, 1)));
+    assert_eq!(into_iter.len(), 7);
+}
+
+#[test]
+fn zip_iterators() {
+    let grid = Grid::new(2, 2, Box::new([1, 2, 3, 4]));
+    for ((pos, value), size_pos) in grid.iter_with_position().zip(grid.size().iter()) {
+        assert_eq!(pos, size_pos);
+        assert_eq!(grid[pos], *value);
+    }
+}
+
+#[test]
+fn split_iterators() {
+    let mut grid = Grid::new(5, 3, Box::new([0; 15]));
+
+    let parts = grid.iter_mut_with_position().split_into(4);
+    assert_eq!(parts.iter().map(|part| part.len()).collect::<Vec<_>>(), vec![4, 4, 4, 3]);
+    for part in parts {
+        for (pos, value) in part {
+            *value = to_grid_index(pos, RectSize::new(5, 3));
+        }
+    }
+    assert_eq!(grid.iter().copied().collect::<Vec<_>>(), (0..15).collect::<Vec<_>>());
+
+    let (front, back) = grid.iter_with_position().split_at(6);
+    assert_eq!(front.map(|(pos, _)| pos).next_back(), Some(Position::new(0, 1)));
+    assert_eq!(back.map(|(pos, _)| pos).next(), Some(Position::new(1, 1)));
+
+    let size = RectSize::new(5, 3);
+    let joined: Vec<_> = size.iter().split_into(2).into_iter().flatten().collect();
+    assert_eq!(joined, size.iter().collect::<Vec<_>>());
+
+    let (_, back) = grid.into_iter_with_position().split_at(14);
+    assert_eq!(back.collect::<Vec<_>>(), vec![(Position::new(4, 2), 14)]);
+
+    let empty = RectSize::new(0, 0);
+    assert!(empty.iter().split_into(3).is_empty());
+}
@@ -0,0 +1,116 @@
+use test_case::test_case;
+use xs_games_rs::dim2::{
+    grid::{Grid, Position, RectSize},
+    noise::{remap, threshold, Noise, NoiseField, NoiseKind},
+};
+
+fn sample_points() -> impl Iterator<Item = (f32, f32)> {
+    (0..2000).map(|i| (i as f32 * 0.137 - 50., (i * 7 % 311) as f32 * 0.291 - 20.))
+}
+
+#[test_case(NoiseKind::Value)]
+#[test_case(NoiseKind::Perlin)]
+#[test_case(NoiseKind::Simplex)]
+fn reproducible_from_seed(kind: NoiseKind) {
+    let (a, b, c) = (Noise::new(kind, 3), Noise::new(kind, 3), Noise::new(kind, 4));
+    assert!(sample_points().all(|(x, y)| a.sample(x, y) == b.sample(x, y)));
+    assert!(sample_points().any(|(x, y)| a.sample(x, y) != c.sample(x, y)));
+}
+
+#[test_case(NoiseKind::Value)]
+#[test_case(NoiseKind::Perlin)]
+#[test_case(NoiseKind::Simplex)]
+fn value_range(kind: NoiseKind) {
+    let noise = Noise::new(kind, 1);
+    let values: Vec<_> = sample_points().map(|(x, y)| noise.sample(x, y)).collect();
+    assert!(values.iter().all(|value| (-1.05..=1.05).contains(value)));
+    // the noise actually varies instead of staying around zero
+    assert!(values.iter().any(|&value| value > 0.2) && values.iter().any(|&value| value < -0.2));
+}
+
+#[test_case(NoiseKind::Value)]
+#[test_case(NoiseKind::Perlin)]
+#[test_case(NoiseKind::Simplex)]
+fn continuous(kind: NoiseKind) {
+    let noise = Noise::new(kind, 2);
+    assert!(sample_points().all(|(x, y)| (noise.sample(x, y) - noise.sample(x + 0.001, y + 0.001)).abs() < 0.05));
+}
+
+#[test]
+fn perlin_zero_at_lattice_points() {
+    let noise = Noise::new(NoiseKind::Perlin, 5);
+    assert!((-10..10).all(|x| (-10..10).all(|y| noise.sample(x as f32, y as f32).abs() < 1e-6)));
+}
+
+#[test]
+fn repeats_every_256_units() {
+    let noise = Noise::new(NoiseKind::Value, 8);
+    assert!((noise.sample(3.3, 7.7) - noise.sample(259.3, 7.7)).abs() < 1e-4);
+}
+
+#[test]
+fn field_to_grid() {
+    let field = NoiseField {
+        frequency: 0.1,
+        octaves: 5,
+        ..NoiseField::new(NoiseKind::Simplex, 9)
+    };
+    let grid = field.to_grid(RectSize::new(16, 8));
+    assert_eq!(grid.size(), RectSize::new(16, 8));
+    assert_eq!(grid[Position::new(5, 3)], field.sample(5., 3.));
+    assert!(grid.iter().all(|value| (-1.05..=1.05).contains(value)));
+}
+
+#[test]
+fn field_without_octaves() {
+    let field = NoiseField {
+        octaves: 0,
+        ..NoiseField::new(NoiseKind::Perlin, 0)
+    };
+    assert_eq!(field.sample(1.5, 2.5), 0.);
+}
+
+#[test]
+fn single_octave_matches_noise() {
+    let field = NoiseField {
+        frequency: 0.5,
+        octaves: 1,
+        ..NoiseField::new(NoiseKind::Value, 6)
+    };
+    assert_eq!(field.sample(3., 5.), field.noise.sample(1.5, 2.5));
+}
+
+#[test_case(0., (-1., 1.), (0., 1.), 0.5)]
+#[test_case(2., (0., 4.), (10., 20.), 15.)]
+#[test_case(-2., (0., 1.), (0., 10.), -20.)]
+fn remap_values(value: f32, from: (f32, f32), to: (f32, f32), expected: f32) {
+    assert_eq!(remap(value, from, to), expected);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Terrain {
+    Water,
+    Land,
+    Mountain,
+}
+
+#[test_case(-0.7, Terrain::Water)]
+#[test_case(-0.2, Terrain::Land)]
+#[test_case(0.5, Terrain::Land)]
+#[test_case(0.6, Terrain::Mountain)]
+#[test_case(3., Terrain::Mountain)]
+fn threshold_bands(value: f32, expected: Terrain) {
+    assert_eq!(threshold(value, &[(-0.2, Terrain::Water), (0.6, Terrain::Land), (1., Terrain::Mountain)]), expected);
+}
+
+#[test]
+fn normalize_and_threshold_grid() {
+    let mut grid = Grid::new(4, 1, Box::new([-0.5, 0., 0.25, 0.5]));
+    assert_eq!(grid.value_range(), Some((-0.5, 0.5)));
+
+    grid.normalize((0., 1.));
+    assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![0., 0.5, 0.75, 1.]);
+
+    let terrain = grid.threshold(&[(0.5, Terrain::Water), (0.8, Terrain::Land), (1., Terrain::Mountain)]);
+    assert_eq!(terrain.into_array().to_vec(), vec![Terrain::Water, Terrain::Land, Terrain::Land, Terrain::Mountain]);
+}
@@ -0,0 +1,64 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use xs_games_rs::dim2::grid::{to_grid_index, Grid, Position, RectSize};
+
+const WIDTH: usize = 300;
+const HEIGHT: usize = 200;
+
+fn create_test_grid() -> Grid<usize> {
+    Grid::new(WIDTH, HEIGHT, (0..WIDTH * HEIGHT).collect::<Vec<_>>().into_boxed_slice())
+}
+
+#[test]
+fn map_matches_sequential() {
+    let grid = create_test_grid();
+    let mapped = grid.par_map_with_position(|pos, value| (pos, value * 2));
+
+    for ((pos, &value), &(mapped_pos, mapped_value)) in grid.iter_with_position().zip(mapped.iter()) {
+        assert_eq!(pos, mapped_pos);
+        assert_eq!(value * 2, mapped_value);
+    }
+    assert_eq!(grid.par_map(|value| value + 1).get(Position::new(WIDTH as i64 - 1, HEIGHT as i64 - 1)), Some(WIDTH * HEIGHT));
+}
+
+#[test]
+fn for_each_visits_everything() {
+    let grid = create_test_grid();
+    let counter = AtomicUsize::new(0);
+    let sum = AtomicUsize::new(0);
+
+    grid.par_for_each_with_position(|pos, &value| {
+        assert_eq!(to_grid_index(pos, RectSize::new(WIDTH, HEIGHT)), value);
+        counter.fetch_add(1, Ordering::Relaxed);
+        sum.fetch_add(value, Ordering::Relaxed);
+    });
+
+    assert_eq!(counter.load(Ordering::Relaxed), WIDTH * HEIGHT);
+    assert_eq!(sum.load(Ordering::Relaxed), (0..WIDTH * HEIGHT).sum());
+}
+
+#[test]
+fn for_each_mut() {
+    let mut grid = Grid::new(WIDTH, HEIGHT, vec![(0, 0); WIDTH * HEIGHT].into_boxed_slice());
+    grid.par_for_each_mut_with_position(|pos, value| *value = (pos.x, pos.y));
+    assert!(grid.iter_with_position().all(|(pos, &value)| value == (pos.x, pos.y)));
+}
+
+#[test]
+fn zip_map() {
+    let grid = create_test_grid();
+    let other = grid.par_map(|value| value % 7);
+    let zipped = grid.par_zip_map(&other, |_, a, b| a - b);
+    assert!(zipped.iter().all(|value| value % 7 == 0));
+
+    let small = Grid::new(3, 1, Box::new([1, 2, 3]));
+    let zipped = small.par_zip_map(&Grid::new(3, 1, Box::new([1., 0.5, 2.])), |pos, a, b| a as f64 * b + pos.x as f64);
+    assert_eq!(zipped.iter().copied().collect::<Vec<_>>(), vec![1., 2., 8.]);
+}
+
+#[test]
+#[should_panic]
+fn zip_map_size_mismatch() {
+    let grid = Grid::new(2, 2, Box::new([0; 4]));
+    grid.par_zip_map(&Grid::new(4, 1, Box::new([0; 4])), |_, a, b| a + b);
+}
@@ -0,0 +1,55 @@
+use test_case::test_case;
+use xs_games_rs::dim2::grid::{position::RoundingPolicy, Position, RectSize};
+
+#[test]
+fn scalar_arithmetic() {
+    let mut position = Position::new(3, -4);
+    assert_eq!(position * 2, Position::new(6, -8));
+    assert_eq!(position / 2, Position::new(1, -2));
+    assert_eq!(-position, Position::new(-3, 4));
+
+    position *= 3;
+    assert_eq!(position, Position::new(9, -12));
+    position /= 3;
+    assert_eq!(position, Position::new(3, -4));
+}
+
+#[test]
+fn component_wise() {
+    let a = Position::new(3, -4);
+    let b = Position::new(-1, 2);
+    assert_eq!(a.component_min(b), Position::new(-1, -4));
+    assert_eq!(a.component_max(b), Position::new(3, 2));
+    assert_eq!(a.abs(), Position::new(3, 4));
+    assert_eq!(a.signum(), Position::new(1, -1));
+    assert_eq!(Position::ZERO.signum(), Position::ZERO);
+}
+
+#[test_case(Position::new(-3, 2), Position::new(0, 2))]
+#[test_case(Position::new(10, 10), Position::new(4, 2))]
+#[test_case(Position::new(1, 1), Position::new(1, 1))]
+fn clamp_to(position: Position, expected: Position) {
+    assert_eq!(position.clamp_to(RectSize::new(5, 3)), expected);
+}
+
+#[test]
+fn usize_conversion() {
+    assert_eq!(<(usize, usize)>::try_from(Position::new(3, 4)), Ok((3, 4)));
+    assert!(<(usize, usize)>::try_from(Position::new(-1, 4)).is_err());
+    assert!(<(usize, usize)>::try_from(Position::new(1, -4)).is_err());
+    assert_eq!(Position::from((3usize, 4usize)), Position::new(3, 4));
+}
+
+#[test_case(RoundingPolicy::Floor, Position::new(2, -3))]
+#[test_case(RoundingPolicy::Round, Position::new(3, -2))]
+#[test_case(RoundingPolicy::Ceil, Position::new(3, -2))]
+#[test_case(RoundingPolicy::Truncate, Position::new(2, -2))]
+fn f32_conversion(rounding: RoundingPolicy, expected: Position) {
+    assert_eq!(Position::from_f32(2.5, -2.4, rounding), expected);
+}
+
+#[test]
+fn to_f32() {
+    assert_eq!(Position::new(-3, 7).to_f32(), (-3., 7.));
+    assert_eq!(<(f32, f32)>::from(Position::new(1, 2)), (1., 2.));
+}
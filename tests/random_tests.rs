@@ -0,0 +1,145 @@
+use test_case::test_case;
+use xs_games_rs::{
+    dim2::{
+        collider::{Collider, RelativeRectangle},
+        grid::RectSize,
+    },
+    Rng,
+};
+
+#[test]
+fn same_seed_same_sequence() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+    let mut c = Rng::new(43);
+    let first: Vec<_> = (0..10).map(|_| a.next_u64()).collect();
+    assert_eq!(first, (0..10).map(|_| b.next_u64()).collect::<Vec<_>>());
+    assert_ne!(first, (0..10).map(|_| c.next_u64()).collect::<Vec<_>>());
+}
+
+#[test]
+fn reference_sequence() {
+    // first outputs of the reference xoshiro256** implementation for the state 1, 2, 3, 4
+    let mut rng = Rng::from_state([1, 2, 3, 4]).unwrap();
+    let expected = [
+        11520,
+        0,
+        1509978240,
+        1215971899390074240,
+        1216172134540287360,
+        607988272756665600,
+        16172922978634559625,
+        8476171486693032832,
+        10595114339597558777,
+        2904607092377533576,
+    ];
+    assert_eq!((0..10).map(|_| rng.next_u64()).collect::<Vec<_>>(), expected);
+
+    // the seed is expanded with splitmix64, whose first output for 0 is 0xe220a8397b1dcdaf
+    assert_eq!(Rng::new(0).state()[0], 0xe220_a839_7b1d_cdaf);
+}
+
+#[test]
+fn ranges() {
+    let mut rng = Rng::new(1);
+    for _ in 0..1000 {
+        assert!((-5..5).contains(&rng.range_i64(-5..5)));
+        assert!((3..4).contains(&rng.range_usize(3..4)));
+        assert!((0. ..1.).contains(&rng.next_f64()));
+        assert!((0. ..1.).contains(&rng.next_f32()));
+    }
+}
+
+#[test]
+fn range_covers_every_value() {
+    let mut rng = Rng::new(2);
+    let mut seen = [false; 6];
+    for _ in 0..200 {
+        seen[rng.range_usize(0..6)] = true;
+    }
+    assert!(seen.iter().all(|&seen| seen));
+}
+
+#[test]
+#[should_panic]
+fn empty_range() {
+    Rng::new(0).range_i64(3..3);
+}
+
+#[test]
+fn saved_state_continues_sequence() {
+    let mut rng = Rng::new(7);
+    rng.next_u64();
+    let saved = rng.to_bytes();
+    let expected: Vec<_> = (0..5).map(|_| rng.next_u64()).collect();
+
+    let mut restored = Rng::from_bytes(saved).unwrap();
+    assert_eq!((0..5).map(|_| restored.next_u64()).collect::<Vec<_>>(), expected);
+    assert_eq!(Rng::from_state(restored.state()), Some(restored));
+}
+
+#[test]
+fn zero_state_rejected() {
+    assert_eq!(Rng::from_bytes([0; 32]), None);
+    assert_eq!(Rng::from_state([0; 4]), None);
+}
+
+#[test]
+fn float_range() {
+    let mut rng = Rng::new(3);
+    assert!((0..1000).all(|_| (-2.5..0.5).contains(&rng.range_f32(-2.5..0.5))));
+}
+
+#[test]
+fn shuffle_keeps_values() {
+    let mut rng = Rng::new(4);
+    let mut values: Vec<_> = (0..20).collect();
+    rng.shuffle(&mut values);
+    assert_ne!(values, (0..20).collect::<Vec<_>>());
+
+    values.sort();
+    assert_eq!(values, (0..20).collect::<Vec<_>>());
+}
+
+#[test]
+fn choose() {
+    let mut rng = Rng::new(5);
+    assert_eq!(rng.choose::<u8>(&[]), None);
+    assert!((0..100).all(|_| [1, 2, 3].contains(rng.choose(&[1, 2, 3]).unwrap())));
+}
+
+#[test]
+fn weighted_choice() {
+    let mut rng = Rng::new(6);
+    let mut counts = [0; 4];
+    for _ in 0..4000 {
+        counts[rng.weighted_index(&[1., 0., 3., -2.]).unwrap()] += 1;
+    }
+    assert_eq!((counts[1], counts[3]), (0, 0));
+    assert!((2700..3300).contains(&counts[2]));
+
+    assert_eq!(rng.weighted_index(&[0., -1.]), None);
+    assert_eq!(rng.choose_weighted(&["common", "never"], |&name| if name == "never" { 0. } else { 1. }), Some(&"common"));
+}
+
+#[test]
+fn position_in_size() {
+    let mut rng = Rng::new(8);
+    let size = RectSize::new(3, 2);
+    let mut seen = vec![false; size.len()];
+    for _ in 0..200 {
+        let pos = rng.position_in(size);
+        seen[(pos.y * 3 + pos.x) as usize] = true;
+    }
+    assert!(seen.iter().all(|&seen| seen));
+}
+
+#[test_case(Collider::Circle(2.))]
+#[test_case(Collider::Rectangle(RelativeRectangle { width: 4., height: 1., offset_x: 2., offset_y: 0.5 }))]
+fn point_in_collider(collider: Collider) {
+    let mut rng = Rng::new(9);
+    for _ in 0..500 {
+        let (x, y) = rng.point_in_collider(&collider, 10., -5.);
+        assert!(collider.is_point_inside(10., -5., x, y));
+    }
+}
@@ -0,0 +1,60 @@
+use xs_games_rs::{
+    dim2::grid::{
+        screen_pathing::{get_shortest_screen_path, screen_path_exists, ScreenPathError},
+        Grid, Position, RectSize, ScreenTranslation,
+    },
+    ScreenView,
+};
+
+#[test]
+fn path_in_screen_space() {
+    let grid = create_test_grid();
+    let translation = create_test_translation();
+
+    let (waypoints, cost) = get_shortest_screen_path(&grid, &translation, (12., 51.), (35., 55.), &|tile| tile)
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(cost, 2);
+    assert_eq!(waypoints, vec![(15., 55.), (25., 55.), (35., 55.)]);
+}
+
+#[test]
+fn blocked_path() {
+    let mut grid = create_test_grid();
+    let translation = create_test_translation();
+    for y in 0..3 {
+        grid.set(Position::new(1, y), false).unwrap();
+    }
+
+    assert_eq!(screen_path_exists(&grid, &translation, (15., 55.), (35., 55.), &|tile| tile), Ok(false));
+}
+
+#[test]
+fn points_out_of_view() {
+    let grid = create_test_grid();
+    let translation = create_test_translation();
+
+    assert_eq!(
+        get_shortest_screen_path(&grid, &translation, (0., 0.), (15., 55.), &|tile| tile),
+        Err(ScreenPathError::StartOutOfView)
+    );
+    assert_eq!(
+        get_shortest_screen_path(&grid, &translation, (15., 55.), (15., 80.), &|tile| tile),
+        Err(ScreenPathError::EndOutOfView)
+    );
+}
+
+fn create_test_grid() -> Grid<bool> {
+    Grid::new(3, 3, Box::new([true; 9]))
+}
+
+fn create_test_translation() -> ScreenTranslation {
+    let screen_view = ScreenView {
+        offset_x: 10.,
+        offset_y: 50.,
+        width: 30.,
+        height: 30.,
+    };
+    ScreenTranslation::new(screen_view, RectSize::new(3, 3))
+}
@@ -1,5 +1,5 @@
 use xs_games_rs::{
-    dim2::grid::{Position, RectSize, ScreenTranslation},
+    dim2::grid::{GridRect, Position, RectSize, ScalingMode, ScreenTranslation, TileCorner, TileEdge},
     ScreenView,
 };
 
@@ -50,3 +50,121 @@ fn create_test_translation_negative() -> ScreenTranslation {
     let logical_size = RectSize { width: 10, height: 10 };
     ScreenTranslation::new(screen_view, logical_size)
 }
+
+#[test]
+fn fixed_tile_size() {
+    let translation = ScreenTranslation::with_tile_size(create_wide_screen_view(), RectSize::new(4, 2), 10., 10.);
+    assert_eq!((translation.tile_width(), translation.tile_height()), (10., 10.));
+    assert_eq!(translation.grid_view(), screen_view(80., 40., 40., 20.));
+    assert_eq!(translation.block_center_to_screen_position(0, 0), (85., 45.));
+    assert_eq!(translation.get_grid_position(119., 59.), Some(Position::new(3, 1)));
+    assert!(translation.get_grid_position(79., 45.).is_none());
+}
+
+#[test]
+fn fit_scaling() {
+    let translation = ScreenTranslation::with_scaling(create_wide_screen_view(), RectSize::new(2, 2), ScalingMode::Fit);
+    assert_eq!((translation.tile_width(), translation.tile_height()), (50., 50.));
+    assert_eq!(translation.used_screen_view(), screen_view(50., 0., 100., 100.));
+    assert_eq!(translation.get_grid_position(50., 0.), Some(Position::new(0, 0)));
+    // letterbox bars do not belong to the grid
+    assert!(translation.get_grid_position(49., 50.).is_none());
+    assert!(translation.get_grid_position(150., 50.).is_none());
+}
+
+#[test]
+fn fill_scaling() {
+    let translation = ScreenTranslation::with_scaling(create_wide_screen_view(), RectSize::new(2, 2), ScalingMode::Fill);
+    assert_eq!((translation.tile_width(), translation.tile_height()), (100., 100.));
+    assert_eq!(translation.grid_view(), screen_view(0., -50., 200., 200.));
+    assert_eq!(translation.used_screen_view(), create_wide_screen_view());
+    assert_eq!(translation.get_grid_position(10., 10.), Some(Position::new(0, 0)));
+    assert_eq!(translation.get_grid_position(190., 90.), Some(Position::new(1, 1)));
+}
+
+#[test]
+fn stretch_scaling() {
+    let translation = ScreenTranslation::with_scaling(create_wide_screen_view(), RectSize::new(2, 2), ScalingMode::Stretch);
+    assert_eq!((translation.tile_width(), translation.tile_height()), (100., 50.));
+    assert_eq!(translation.used_screen_view(), create_wide_screen_view());
+}
+
+fn create_wide_screen_view() -> ScreenView {
+    screen_view(0., 0., 200., 100.)
+}
+
+fn screen_view(offset_x: f32, offset_y: f32, width: f32, height: f32) -> ScreenView {
+    ScreenView {
+        offset_x,
+        offset_y,
+        width,
+        height,
+    }
+}
+
+#[test]
+fn tile_point() {
+    let translation = create_test_translation();
+    let tile_point = translation.get_tile_point(32.5, 57.5).unwrap();
+    assert_eq!(tile_point.position, Position::new(2, 0));
+    assert_eq!((tile_point.local_x, tile_point.local_y), (0.25, 0.75));
+    assert!(translation.get_tile_point(5., 57.5).is_none());
+}
+
+#[test]
+fn nearest_edge_and_corner() {
+    let translation = create_test_translation();
+    assert_eq!(translation.get_nearest_edge(32.5, 56.), Some((Position::new(2, 0), TileEdge::Left)));
+    assert_eq!(translation.get_nearest_edge(35., 59.), Some((Position::new(2, 0), TileEdge::Bottom)));
+    assert_eq!(translation.get_nearest_corner(38., 52.), Some((Position::new(2, 0), TileCorner::TopRight)));
+    assert_eq!(TileEdge::normalize(Position::new(2, 0), TileEdge::Bottom), (Position::new(2, 1), TileEdge::Top));
+    assert_eq!(TileCorner::normalize(Position::new(2, 0), TileCorner::BottomRight), (Position::new(3, 1), TileCorner::TopLeft));
+    assert_eq!(TileCorner::normalize(Position::new(2, 0), TileCorner::TopLeft), (Position::new(2, 0), TileCorner::TopLeft));
+}
+
+#[test]
+fn edge_and_corner_to_screen() {
+    let translation = create_test_translation();
+    assert_eq!(translation.edge_to_screen_line(Position::new(2, 0), TileEdge::Right), ((40., 50.), (40., 60.)));
+    assert_eq!(translation.edge_center_to_screen_position(Position::new(2, 0), TileEdge::Top), (35., 50.));
+    assert_eq!(translation.corner_to_screen_position(Position::new(2, 0), TileCorner::BottomLeft), (30., 60.));
+}
+
+#[test]
+fn screen_rect_to_grid_region() {
+    let translation = create_test_translation();
+    let region = translation.get_grid_region(ScreenView::from_corners(45., 95., 22., 61.));
+    assert_eq!(region, Some(GridRect::new(Position::new(1, 1), RectSize::new(3, 4))));
+
+    // clamped to the screen view
+    let region = translation.get_grid_region(screen_view(-100., 140., 500., 500.));
+    assert_eq!(region, Some(GridRect::new(Position::new(0, 9), RectSize::new(10, 1))));
+
+    assert!(translation.get_grid_region(screen_view(200., 0., 10., 10.)).is_none());
+}
+
+#[test]
+fn single_point_selection() {
+    let translation = create_test_translation();
+    let region = translation.get_grid_region(screen_view(30., 60., 0., 0.));
+    assert_eq!(region, Some(GridRect::new(Position::new(2, 1), RectSize::new(1, 1))));
+}
+
+#[test]
+fn selection_on_view_edge() {
+    let translation = create_test_translation();
+    // the right and bottom edges of the view belong to no tile
+    assert!(translation.get_grid_region(screen_view(110., 60., 0., 20.)).is_none());
+    assert!(translation.get_grid_region(screen_view(30., 150., 20., 0.)).is_none());
+    assert!(translation.get_grid_region(screen_view(110., 60., 10., 10.)).is_none());
+    assert_eq!(translation.get_grid_region(screen_view(109., 149., 0., 0.)), Some(GridRect::new(Position::new(9, 9), RectSize::new(1, 1))));
+
+    let touching = screen_view(110., 50., 10., 10.);
+    assert_eq!(touching.intersection(&screen_view(10., 50., 100., 100.)), None);
+}
+
+#[test]
+fn grid_region_to_screen_rect() {
+    let translation = create_test_translation();
+    assert_eq!(translation.grid_region_to_screen(GridRect::new(Position::new(1, 2), RectSize::new(3, 4))), screen_view(20., 70., 30., 40.));
+}
@@ -0,0 +1,95 @@
+use test_case::test_case;
+use xs_games_rs::dim2::grid::{
+    generators::Tile,
+    pathing::get_shortest_path,
+    Grid, Position, RectSize, TextMapError,
+};
+
+fn to_tile(character: char) -> Option<Tile> {
+    match character {
+        '#' => Some(Tile::Wall),
+        '.' => Some(Tile::Floor),
+        _ => None,
+    }
+}
+
+fn to_char(tile: Tile) -> char {
+    match tile {
+        Tile::Wall => '#',
+        Tile::Floor => '.',
+    }
+}
+
+#[test]
+fn parse_inline_map() {
+    let grid = Grid::from_text(
+        r"
+#####
+#..##
+##..#
+#####
+",
+        to_tile,
+    )
+    .unwrap();
+
+    assert_eq!(grid.size(), RectSize::new(5, 4));
+    assert_eq!(grid[Position::new(1, 1)], Tile::Floor);
+    assert_eq!(grid[Position::new(1, 2)], Tile::Wall);
+    let (_, cost) = get_shortest_path(&grid, Position::new(1, 1), Position::new(3, 2), &Tile::is_floor).unwrap();
+    assert_eq!(cost, 3);
+}
+
+#[test]
+fn indented_map() {
+    let grid = Grid::from_text(
+        r"
+            #####
+            #..##
+             ....
+        ",
+        to_tile,
+    );
+    // the third row is indented one more character than the others
+    assert_eq!(grid.err(), Some(TextMapError::UnknownChar { character: ' ', position: Position::new(0, 2) }));
+
+    let grid = Grid::from_text(
+        r"
+            #####
+            #..##
+            ##..#
+        ",
+        to_tile,
+    )
+    .unwrap();
+    assert_eq!(grid.to_text(to_char), "#####\n#..##\n##..#");
+}
+
+#[test]
+fn round_trip() {
+    let text = "#.#\n...\n##.";
+    let grid = Grid::from_text(text, to_tile).unwrap();
+    assert_eq!(grid.to_text(to_char), text);
+}
+
+#[test]
+fn multi_byte_characters() {
+    let grid = Grid::from_text("~≈~\n≈~≈", |character| Some(character == '≈')).unwrap();
+    assert_eq!(grid.size(), RectSize::new(3, 2));
+    assert_eq!(grid.to_text(|deep| if deep { '≈' } else { '~' }), "~≈~\n≈~≈");
+}
+
+#[test_case("", TextMapError::Empty)]
+#[test_case("\n  \n", TextMapError::Empty)]
+#[test_case("###\n#.\n###", TextMapError::RaggedRow { row: 1, expected_width: 3, width: 2 })]
+#[test_case("\n##\n##.\n", TextMapError::RaggedRow { row: 1, expected_width: 2, width: 3 })]
+#[test_case("##\n#x", TextMapError::UnknownChar { character: 'x', position: Position::new(1, 1) })]
+fn parse_errors(text: &str, expected: TextMapError) {
+    assert_eq!(Grid::from_text(text, to_tile).err(), Some(expected));
+}
+
+#[test]
+fn error_messages() {
+    let error = Grid::from_text("##\n#", to_tile).err().unwrap();
+    assert_eq!(error.to_string(), "row 1 has 1 characters, but the rows before have 2");
+}
@@ -0,0 +1,64 @@
+use xs_games_rs::{
+    dim2::grid::{Position, RectSize, ScalingMode, ScreenTranslation, ViewportManager},
+    ScreenView,
+};
+
+#[test]
+fn split_screen() {
+    let viewports = create_test_viewports();
+
+    assert_eq!(viewports.get_grid_position(15., 15.), Some((&"player 1", Some(Position::new(1, 1)))));
+    assert_eq!(viewports.get_grid_position(115., 15.), Some((&"player 2", Some(Position::new(1, 1)))));
+    assert_eq!(viewports.get_grid_position(300., 15.), None);
+}
+
+#[test]
+fn minimap_on_top() {
+    let viewports = create_test_viewports();
+
+    // the minimap covers the bottom right corner of player 2 with 2 pixel tiles
+    assert_eq!(viewports.viewport_at(185., 85.).map(|(name, _)| *name), Some("minimap"));
+    assert_eq!(viewports.get_grid_position(183., 85.), Some((&"minimap", Some(Position::new(2, 2)))));
+    // letterbox area of the minimap does not fall through to player 2
+    assert_eq!(viewports.get_grid_position(199., 81.), Some((&"minimap", None)));
+}
+
+#[test]
+fn replace_and_remove() {
+    let mut viewports = create_test_viewports();
+    assert_eq!(viewports.len(), 3);
+
+    viewports.insert("player 1", create_translation(0., 0., 200., 100., 10));
+    assert_eq!(viewports.len(), 3);
+    assert_eq!(viewports.iter().next().map(|(name, _)| *name), Some("player 1"));
+    // player 2 is still drawn above the enlarged player 1 viewport
+    assert_eq!(viewports.viewport_at(115., 15.).map(|(name, _)| *name), Some("player 2"));
+
+    assert!(viewports.remove(&"player 2").is_some());
+    assert_eq!(viewports.get_grid_position(115., 15.), Some((&"player 1", Some(Position::new(5, 1)))));
+    assert!(viewports.remove(&"player 2").is_none());
+}
+
+fn create_test_viewports() -> ViewportManager<&'static str> {
+    let mut viewports = ViewportManager::new();
+    viewports.insert("player 1", create_translation(0., 0., 100., 100., 10));
+    viewports.insert("player 2", create_translation(100., 0., 100., 100., 10));
+    viewports.insert(
+        "minimap",
+        ScreenTranslation::with_scaling(view(176., 80., 24., 20.), RectSize::new(10, 10), ScalingMode::Fit),
+    );
+    viewports
+}
+
+fn create_translation(offset_x: f32, offset_y: f32, width: f32, height: f32, tiles: usize) -> ScreenTranslation {
+    ScreenTranslation::new(view(offset_x, offset_y, width, height), RectSize::new(tiles, tiles))
+}
+
+fn view(offset_x: f32, offset_y: f32, width: f32, height: f32) -> ScreenView {
+    ScreenView {
+        offset_x,
+        offset_y,
+        width,
+        height,
+    }
+}
@@ -0,0 +1,206 @@
+use test_case::test_case;
+use xs_games_rs::{
+    dim2::grid::{
+        generators::{
+            wave_function_collapse::WaveFunctionCollapseError, AdjacencyRules, SampleTransform, SampleTransforms, WaveFunctionCollapse,
+        },
+        Direction, Grid, RectSize, WrapMode,
+    },
+    Rng,
+};
+
+fn assert_follows_rules<T: Copy + PartialEq>(grid: &Grid<T>, rules: &AdjacencyRules<T>) {
+    for (pos, &tile) in grid.iter_with_position() {
+        for direction in Direction::CARDINAL {
+            if let Some(neighbor) = grid.get(pos.neighbor(direction)) {
+                assert!(rules.is_allowed(tile, direction, neighbor));
+            }
+        }
+    }
+}
+
+#[test]
+fn checkerboard_from_sample() {
+    let sample = Grid::new(2, 2, Box::new([0, 1, 1, 0]));
+    let rules = AdjacencyRules::from_sample(&sample, SampleTransforms::None, |tile, _| tile);
+    let grid = WaveFunctionCollapse::new(RectSize::new(7, 5)).generate(&rules, &mut Rng::new(0)).unwrap();
+
+    assert_follows_rules(&grid, &rules);
+    let first = grid.iter().next().copied().unwrap();
+    assert!(grid.iter_with_position().all(|(pos, &tile)| (tile == first) == ((pos.x + pos.y) % 2 == 0)));
+}
+
+#[test]
+fn explicit_rules() {
+    // water only touches sand, sand touches everything, grass only touches sand and itself
+    let (water, sand, grass) = ('~', '.', '"');
+    let mut rules = AdjacencyRules::new();
+    for direction in Direction::CARDINAL {
+        rules.allow(water, direction, water);
+        rules.allow(water, direction, sand);
+        rules.allow(sand, direction, sand);
+        rules.allow(sand, direction, grass);
+        rules.allow(grass, direction, grass);
+    }
+    assert!(!rules.is_allowed(water, Direction::Up, grass));
+
+    let grid = WaveFunctionCollapse::new(RectSize::new(20, 20)).generate(&rules, &mut Rng::new(5)).unwrap();
+    assert_follows_rules(&grid, &rules);
+}
+
+#[test]
+fn reproducible_from_seed() {
+    let sample = Grid::new(4, 3, Box::new([0, 0, 1, 2, 0, 1, 1, 2, 1, 1, 2, 2]));
+    let rules = AdjacencyRules::from_sample(&sample, SampleTransforms::All, |tile, _| tile);
+    let generator = WaveFunctionCollapse::new(RectSize::new(12, 12));
+
+    let first = generator.generate(&rules, &mut Rng::new(9)).unwrap();
+    let second = generator.generate(&rules, &mut Rng::new(9)).unwrap();
+    assert_eq!(first.iter().collect::<Vec<_>>(), second.iter().collect::<Vec<_>>());
+    assert_follows_rules(&first, &rules);
+}
+
+#[test_case(SampleTransforms::None, [true, false, false, false])]
+#[test_case(SampleTransforms::Rotations, [true, true, true, true])]
+#[test_case(SampleTransforms::Reflections, [true, true, false, false])]
+#[test_case(SampleTransforms::All, [true, true, true, true])]
+fn sample_transforms(transforms: SampleTransforms, expected: [bool; 4]) {
+    // 2 is only ever to the right of 1
+    let rules = AdjacencyRules::from_sample(&Grid::new(2, 1, Box::new([1, 2])), transforms, |tile, _| tile);
+    let allowed = [Direction::Right, Direction::Left, Direction::Down, Direction::Up].map(|direction| rules.is_allowed(1, direction, 2));
+    assert_eq!(allowed, expected);
+}
+
+/// pipe tile connecting to the sides whose bits are set, in the order of `Direction::CARDINAL`
+fn connects(tile: u8, direction: Direction) -> bool {
+    let index = Direction::CARDINAL.iter().position(|&cardinal| cardinal == direction).unwrap();
+    tile & (1 << index) != 0
+}
+
+fn transform_pipe(tile: u8, transform: SampleTransform) -> u8 {
+    Direction::CARDINAL
+        .iter()
+        .enumerate()
+        .filter(|&(_, &direction)| connects(tile, direction))
+        .map(|(_, &direction)| 1 << Direction::CARDINAL.iter().position(|&cardinal| cardinal == transform.apply(direction)).unwrap())
+        .sum()
+}
+
+#[test_case(SampleTransforms::Rotations)]
+#[test_case(SampleTransforms::Reflections)]
+#[test_case(SampleTransforms::All)]
+fn directional_tiles(transforms: SampleTransforms) {
+    // a closed loop of corner pipes, up = 1, right = 2, down = 4, left = 8
+    let sample = Grid::new(2, 2, Box::new([2 | 4, 8 | 4, 2 | 1, 8 | 1]));
+    let rules = AdjacencyRules::from_sample(&sample, transforms, transform_pipe);
+
+    // pipes only connect to pipes that connect back
+    for &tile in rules.tiles() {
+        for &neighbor in rules.tiles() {
+            for direction in Direction::CARDINAL {
+                if rules.is_allowed(tile, direction, neighbor) {
+                    assert_eq!(connects(tile, direction), connects(neighbor, direction.opposite()));
+                }
+            }
+        }
+    }
+    assert!(rules.weights().iter().all(|&weight| weight > 0.));
+}
+
+#[test]
+fn rotated_straight_pipes() {
+    let (horizontal, vertical) = ('-', '|');
+    let rotate = |tile: char, transform: SampleTransform| {
+        if transform.quarter_turns % 2 == 1 {
+            if tile == horizontal { vertical } else { horizontal }
+        } else {
+            tile
+        }
+    };
+    let rules = AdjacencyRules::from_sample(&Grid::new(3, 1, Box::new([horizontal; 3])), SampleTransforms::Rotations, rotate);
+
+    assert_eq!(rules.tiles(), &[horizontal, vertical]);
+    assert!(rules.is_allowed(horizontal, Direction::Right, horizontal));
+    assert!(rules.is_allowed(vertical, Direction::Down, vertical));
+    assert!(!rules.is_allowed(horizontal, Direction::Down, horizontal));
+    assert!(!rules.is_allowed(vertical, Direction::Right, vertical));
+}
+
+#[test]
+fn learned_tiles() {
+    let rules = AdjacencyRules::from_sample(&Grid::new(3, 1, Box::new([4, 4, 8])), SampleTransforms::None, |tile, _| tile);
+    assert_eq!(rules.tiles(), &[4, 8]);
+    assert_eq!(rules.weights(), &[2., 1.]);
+}
+
+#[test]
+fn weights_from_sample() {
+    // 1 appears in one of 16 cells, so it should be rare in the output as well
+    let mut values = [0; 16];
+    values[5] = 1;
+    let rules = AdjacencyRules::from_sample(&Grid::new(4, 4, Box::new(values)), SampleTransforms::None, |tile, _| tile);
+    assert_eq!(rules.weights(), &[15., 1.]);
+
+    let grid = WaveFunctionCollapse::new(RectSize::new(40, 40)).generate(&rules, &mut Rng::new(3)).unwrap();
+    assert_follows_rules(&grid, &rules);
+    assert!(grid.iter().filter(|&&tile| tile == 1).count() < 400);
+}
+
+#[test]
+fn wrapping_contradiction() {
+    // a checkerboard can not continue across the border of an odd width
+    let rules = AdjacencyRules::from_sample(&Grid::new(2, 2, Box::new([0, 1, 1, 0])), SampleTransforms::None, |tile, _| tile);
+    let generator = WaveFunctionCollapse {
+        wrap_mode: WrapMode::Horizontal,
+        ..WaveFunctionCollapse::new(RectSize::new(3, 2))
+    };
+    assert_eq!(generator.generate(&rules, &mut Rng::new(0)).err(), Some(WaveFunctionCollapseError::Contradiction));
+
+    let generator = WaveFunctionCollapse {
+        size: RectSize::new(4, 2),
+        ..generator
+    };
+    assert!(generator.generate(&rules, &mut Rng::new(0)).is_ok());
+}
+
+#[test]
+fn tile_without_allowed_neighbors() {
+    // a single tile that may not be next to itself only fits on a grid without neighbors
+    let mut rules = AdjacencyRules::new();
+    rules.add_tile('#', 1.);
+    let result = WaveFunctionCollapse::new(RectSize::new(2, 1)).generate(&rules, &mut Rng::new(0));
+    assert_eq!(result.err(), Some(WaveFunctionCollapseError::Contradiction));
+
+    let grid = WaveFunctionCollapse::new(RectSize::new(1, 1)).generate(&rules, &mut Rng::new(0)).unwrap();
+    assert_eq!(grid.iter().collect::<Vec<_>>(), vec![&'#']);
+}
+
+#[test]
+fn three_colored_torus() {
+    // neighbors always differ, propagation alone can not see every dead end of this coloring
+    let mut rules = AdjacencyRules::new();
+    for (a, b) in [(0, 1), (1, 2), (2, 0)] {
+        for direction in Direction::CARDINAL {
+            rules.allow(a, direction, b);
+        }
+    }
+    let generator = WaveFunctionCollapse {
+        wrap_mode: WrapMode::Both,
+        ..WaveFunctionCollapse::new(RectSize::new(5, 5))
+    };
+
+    for seed in 0..10 {
+        let grid = generator.generate(&rules, &mut Rng::new(seed)).unwrap();
+        assert_follows_rules(&grid, &rules);
+        assert!(grid.iter_with_position().all(|(pos, &tile)| Direction::CARDINAL
+            .into_iter()
+            .all(|direction| grid.get_wrapped(pos.neighbor(direction), WrapMode::Both) != Some(tile))));
+    }
+}
+
+#[test]
+fn no_tiles() {
+    let rules = AdjacencyRules::<u8>::new();
+    let result = WaveFunctionCollapse::new(RectSize::new(2, 2)).generate(&rules, &mut Rng::new(0));
+    assert_eq!(result.err(), Some(WaveFunctionCollapseError::NoTiles));
+}
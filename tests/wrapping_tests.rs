@@ -0,0 +1,98 @@
+use test_case::test_case;
+use xs_games_rs::dim2::grid::{
+    line_of_sight::{has_line_of_sight, has_wrapped_line_of_sight, line_positions},
+    pathing::{get_shortest_path, get_shortest_wrapped_path, wrapped_path_exists},
+    patterns::surrounding_pattern,
+    position::DistanceMetric,
+    wrapping::get_wrapped_grid_values_from_pattern,
+    Grid, Position, RectSize, WrapMode,
+};
+
+fn create_test_grid() -> Grid<i32> {
+    // 0 1 2 3
+    // 4 5 6 7
+    // 8 9 10 11
+    Grid::new(4, 3, (0..12).collect::<Vec<_>>().into_boxed_slice())
+}
+
+#[test_case(WrapMode::None, Position::new(-1, 0), None)]
+#[test_case(WrapMode::Both, Position::new(-1, 0), Some(Position::new(3, 0)))]
+#[test_case(WrapMode::Both, Position::new(9, -4), Some(Position::new(1, 2)))]
+#[test_case(WrapMode::Horizontal, Position::new(4, 1), Some(Position::new(0, 1)))]
+#[test_case(WrapMode::Horizontal, Position::new(0, 3), None)]
+#[test_case(WrapMode::Vertical, Position::new(0, 3), Some(Position::new(0, 0)))]
+#[test_case(WrapMode::Vertical, Position::new(4, 1), None)]
+fn wrap(wrap_mode: WrapMode, position: Position, expected: Option<Position>) {
+    assert_eq!(wrap_mode.wrap(position, RectSize::new(4, 3)), expected);
+}
+
+#[test]
+fn get_wrapped() {
+    let mut grid = create_test_grid();
+    assert_eq!(grid.get_wrapped(Position::new(-1, -1), WrapMode::Both), Some(11));
+    assert_eq!(grid.get_wrapped(Position::new(-1, -1), WrapMode::Horizontal), None);
+    assert_eq!(grid.get_wrapped(Position::new(2, 1), WrapMode::None), Some(6));
+
+    *grid.get_mut_wrapped(Position::new(5, 0), WrapMode::Both).unwrap() = 42;
+    assert_eq!(grid[Position::new(1, 0)], 42);
+}
+
+#[test]
+fn pattern_values_wrap() {
+    let grid = create_test_grid();
+    let pattern = surrounding_pattern(1);
+    let mut values = get_wrapped_grid_values_from_pattern(&grid, Position::new(0, 0), &*pattern, WrapMode::Both).to_vec();
+    values.sort();
+    assert_eq!(values, vec![1, 3, 4, 5, 7, 8, 9, 11]);
+
+    let values = get_wrapped_grid_values_from_pattern(&grid, Position::new(0, 0), &*pattern, WrapMode::Vertical);
+    assert_eq!(values.len(), 5);
+}
+
+#[test_case(WrapMode::None, (3, 2), 5.)]
+#[test_case(WrapMode::Horizontal, (1, 2), 3.)]
+#[test_case(WrapMode::Vertical, (3, 1), 4.)]
+#[test_case(WrapMode::Both, (1, 1), 2.)]
+fn wrapped_distance(wrap_mode: WrapMode, delta: (i64, i64), manhattan: f64) {
+    let size = RectSize::new(4, 3);
+    let (a, b) = (Position::new(0, 0), Position::new(3, 2));
+    assert_eq!(wrap_mode.delta(a, b, size).0.abs(), delta.0);
+    assert_eq!(wrap_mode.delta(a, b, size).1.abs(), delta.1);
+    assert_eq!(DistanceMetric::Manhattan.wrapped_distance(a, b, size, wrap_mode), manhattan);
+}
+
+#[test]
+fn wrapped_path_crosses_border() {
+    // wall in the middle column, only the way across the border is open
+    let grid = Grid::new(5, 1, Box::new([true, true, false, true, true]));
+    let (start, end) = (Position::new(1, 0), Position::new(3, 0));
+
+    assert_eq!(get_shortest_path(&grid, start, end, &|open| open), None);
+    assert!(!wrapped_path_exists(&grid, start, end, WrapMode::Vertical, &|open| open));
+
+    let (path, cost) = get_shortest_wrapped_path(&grid, start, end, WrapMode::Horizontal, &|open| open).unwrap();
+    assert_eq!(cost, 3);
+    assert_eq!(path, vec![start, Position::new(0, 0), Position::new(4, 0), end]);
+}
+
+#[test]
+fn line() {
+    assert_eq!(
+        line_positions(Position::new(0, 0), Position::new(3, 1)),
+        vec![Position::new(0, 0), Position::new(1, 0), Position::new(2, 1), Position::new(3, 1)]
+    );
+    assert_eq!(line_positions(Position::new(2, 2), Position::new(2, -1)).len(), 4);
+    assert_eq!(line_positions(Position::new(1, 1), Position::new(1, 1)), vec![Position::new(1, 1)]);
+}
+
+#[test]
+fn line_of_sight() {
+    // a wall at x = 2 that is visible itself but blocks everything behind it
+    let grid = Grid::new(6, 1, Box::new([true, true, false, true, true, true]));
+    let is_transparent = |open: bool| open;
+
+    assert!(has_line_of_sight(&grid, Position::new(0, 0), Position::new(2, 0), &is_transparent));
+    assert!(!has_line_of_sight(&grid, Position::new(0, 0), Position::new(4, 0), &is_transparent));
+    assert!(has_wrapped_line_of_sight(&grid, Position::new(0, 0), Position::new(4, 0), WrapMode::Horizontal, &is_transparent));
+    assert!(!has_wrapped_line_of_sight(&grid, Position::new(0, 0), Position::new(3, 0), WrapMode::Horizontal, &is_transparent));
+}